@@ -4,6 +4,7 @@
 use crate::action_private::internal::PdfActionPrivate;
 use crate::bindgen::FPDF_ACTION;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 
 pub struct PdfActionLaunch<'a> {
     handle: FPDF_ACTION,
@@ -18,6 +19,17 @@ impl<'a> PdfActionLaunch<'a> {
     ) -> Self {
         PdfActionLaunch { handle, bindings }
     }
+
+    /// Returns the file specification of the file or application launched by this
+    /// [PdfActionLaunch], typically a file path.
+    ///
+    /// The file is not opened or executed; this function only reports which file
+    /// the action would launch, making it possible to detect documents that attempt to
+    /// launch external programs.
+    #[inline]
+    pub fn file_path(&self) -> Result<String, PdfiumError> {
+        self.file_path_impl()
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionLaunch<'a> {
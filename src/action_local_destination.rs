@@ -43,7 +43,11 @@ impl<'a> PdfActionLocalDestination<'a> {
                 ))
             }
         } else {
-            Ok(PdfDestination::from_pdfium(handle, self.bindings))
+            Ok(PdfDestination::from_pdfium(
+                handle,
+                self.document,
+                self.bindings,
+            ))
         }
     }
 }
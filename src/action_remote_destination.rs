@@ -4,6 +4,9 @@
 use crate::action_private::internal::PdfActionPrivate;
 use crate::bindgen::FPDF_ACTION;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::destination::PdfDestination;
+use crate::document::PdfDocument;
+use crate::error::PdfiumError;
 
 pub struct PdfActionRemoteDestination<'a> {
     handle: FPDF_ACTION,
@@ -18,6 +21,41 @@ impl<'a> PdfActionRemoteDestination<'a> {
     ) -> Self {
         PdfActionRemoteDestination { handle, bindings }
     }
+
+    /// Returns the file specification of the `PdfDocument` targeted by this
+    /// [PdfActionRemoteDestination], typically a file path relative to the location
+    /// of the document containing this action.
+    #[inline]
+    pub fn file_path(&self) -> Result<String, PdfiumError> {
+        self.file_path_impl()
+    }
+
+    /// Returns the target [PdfDestination] for this [PdfActionRemoteDestination].
+    ///
+    /// The destination is located in a different document to the one containing this action,
+    /// so the given [PdfDocument] must be the target document loaded from the path returned
+    /// by the [PdfActionRemoteDestination::file_path()] function. Explicit destinations
+    /// refer to their target page by number, and named destinations are looked up in the
+    /// given document; if the given document does not define a named destination with
+    /// a matching name, `PdfiumError::NoDestinationForAction` is returned.
+    pub fn destination<'b>(
+        &self,
+        document: &'b PdfDocument<'b>,
+    ) -> Result<PdfDestination<'b>, PdfiumError> {
+        let handle = document
+            .bindings()
+            .FPDFAction_GetDest(*document.handle(), self.handle);
+
+        if handle.is_null() {
+            Err(PdfiumError::NoDestinationForAction)
+        } else {
+            Ok(PdfDestination::from_pdfium(
+                handle,
+                *document.handle(),
+                document.bindings(),
+            ))
+        }
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionRemoteDestination<'a> {
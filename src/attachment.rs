@@ -3,7 +3,7 @@
 
 use crate::bindgen::{FPDF_ATTACHMENT, FPDF_WCHAR};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::io::Write;
@@ -86,6 +86,75 @@ impl<'a> PdfAttachment<'a> {
         get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
     }
 
+    /// Returns the string value associated with the given key in the parameters dictionary
+    /// of this [PdfAttachment], or `None` if the key is not present.
+    ///
+    /// The parameters dictionary of an embedded file holds optional information about the file,
+    /// such as its `CreationDate`, `ModDate`, and `CheckSum`. Pdfium sets the `CheckSum` key
+    /// automatically when the file's data is set.
+    pub fn string_value(&self, key: &str) -> Option<String> {
+        if !self
+            .bindings()
+            .is_true(self.bindings().FPDFAttachment_HasKey(self.handle, key))
+        {
+            return None;
+        }
+
+        // Retrieving the value from Pdfium is a two-step operation. First, we call
+        // FPDFAttachment_GetStringValue() with a null buffer; this will retrieve the length of
+        // the value in bytes. Then we reserve a byte buffer of the given length and call
+        // FPDFAttachment_GetStringValue() again with a pointer to the buffer; this will write
+        // the value to the buffer in UTF16-LE format.
+
+        let buffer_length = self.bindings().FPDFAttachment_GetStringValue(
+            self.handle,
+            key,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFAttachment_GetStringValue(
+            self.handle,
+            key,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+
+    /// Sets the string value associated with the given key in the parameters dictionary
+    /// of this [PdfAttachment], replacing any existing value.
+    ///
+    /// Note that the MIME type of an embedded file is stored in the `Subtype` entry of the
+    /// embedded file stream itself rather than in its parameters dictionary, so it cannot be
+    /// set using this function; Pdfium does not currently provide a way to set it.
+    pub fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        if self
+            .bindings()
+            .is_true(
+                self.bindings()
+                    .FPDFAttachment_SetStringValue_str(self.handle, key, value),
+            )
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
     /// Returns the size of this [PdfAttachment] in bytes.
     pub fn len(&self) -> usize {
         // Calling FPDFAttachment_GetFile() with a null buffer will retrieve the length of the
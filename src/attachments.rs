@@ -118,6 +118,11 @@ impl<'a> PdfAttachments<'a> {
     /// Attempts to add a new [PdfAttachment] to this collection, using the given name and the
     /// data in the given byte buffer. An error will be returned if the given name is not
     /// unique in the list of attachments already present in the containing PDF document.
+    ///
+    /// Pdfium does not allow two attachments to share the same name. If an attachment with
+    /// the given name already exists, [PdfiumError::AttachmentNameAlreadyExists] is returned
+    /// and the existing attachment is left unchanged; delete the existing attachment first
+    /// to replace it.
     pub fn create_attachment_from_bytes(
         &mut self,
         name: &str,
@@ -131,7 +136,12 @@ impl<'a> PdfAttachments<'a> {
             .FPDFDoc_AddAttachment_str(self.document_handle, name);
 
         if handle.is_null() {
-            if let Some(error) = self.bindings().get_pdfium_last_error() {
+            if self.iter().any(|attachment| attachment.name() == name) {
+                // Pdfium refuses to create an attachment with the same name as an
+                // existing attachment, without setting an error code.
+
+                Err(PdfiumError::AttachmentNameAlreadyExists)
+            } else if let Some(error) = self.bindings().get_pdfium_last_error() {
                 Err(PdfiumError::PdfiumLibraryInternalError(error))
             } else {
                 // This would be an unusual situation; a null handle indicating failure,
@@ -10,7 +10,21 @@ use std::f32::consts::{FRAC_PI_2, PI};
 use std::os::raw::c_int;
 
 #[cfg(feature = "image")]
-use image::{DynamicImage, ImageBuffer};
+use image::imageops::{resize, FilterType};
+
+#[cfg(feature = "image")]
+use image::codecs::jpeg::{JpegEncoder, PixelDensity, PixelDensityUnit};
+
+#[cfg(feature = "image")]
+use image::codecs::png::PngEncoder;
+
+#[cfg(feature = "image")]
+use image::{
+    ColorType, DynamicImage, GrayImage, ImageBuffer, ImageEncoder, ImageFormat, RgbImage, RgbaImage,
+};
+
+#[cfg(feature = "image")]
+use std::io::{Cursor, Write};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::{Clamped, JsValue};
@@ -234,8 +248,21 @@ impl<'a> PdfBitmap<'a> {
         PdfBitmapFormat::from_pdfium(self.bindings.FPDFBitmap_GetFormat(self.handle) as u32)
     }
 
+    /// Returns the number of bytes in each row of the bitmap buffer backing this [PdfBitmap].
+    ///
+    /// The stride may be larger than the width of the image multiplied by the number of
+    /// bytes per pixel of its [PdfBitmapFormat], since Pdfium may pad each row for alignment.
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.bindings.FPDFBitmap_GetStride(self.handle) as usize
+    }
+
     /// Returns an immutable reference to the bitmap buffer backing this [PdfBitmap].
-    pub fn as_bytes(&self) -> &'a [u8] {
+    ///
+    /// No copy of the buffer is made. The buffer contains [PdfBitmap::height()] rows of
+    /// [PdfBitmap::stride()] bytes each; the layout of each pixel within a row is given by
+    /// [PdfBitmap::format()].
+    pub fn as_bytes(&self) -> &[u8] {
         let buffer_length = self.bindings.FPDFBitmap_GetStride(self.handle)
             * self.bindings.FPDFBitmap_GetHeight(self.handle);
 
@@ -244,20 +271,300 @@ impl<'a> PdfBitmap<'a> {
         unsafe { std::slice::from_raw_parts(buffer_start as *const u8, buffer_length as usize) }
     }
 
+    /// Returns a mutable reference to the bitmap buffer backing this [PdfBitmap], allowing
+    /// the rendered image to be modified in place, for instance to blend a watermark or draw
+    /// redaction boxes before encoding the image.
+    ///
+    /// No copy of the buffer is made. The buffer contains [PdfBitmap::height()] rows of
+    /// [PdfBitmap::stride()] bytes each; the layout of each pixel within a row is given by
+    /// [PdfBitmap::format()]. Any padding bytes at the end of each row are not part of the image.
+    ///
+    /// This function is not available when compiling to WASM, since Pdfium's buffer is then
+    /// held in the memory of a separate WASM module and cannot be borrowed directly.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        let buffer_length = self.stride() * self.height() as usize;
+
+        let buffer_start = self.bindings.FPDFBitmap_GetBuffer(self.handle);
+
+        unsafe { std::slice::from_raw_parts_mut(buffer_start as *mut u8, buffer_length) }
+    }
+
+    /// Multiplies the color channels of each pixel in the bitmap buffer backing this [PdfBitmap]
+    /// by the pixel's alpha channel, converting straight alpha to premultiplied alpha.
+    /// Bitmaps without an alpha channel are left unchanged.
+    pub(crate) fn premultiply_alpha(&mut self) -> Result<(), PdfiumError> {
+        if self.format()? != PdfBitmapFormat::BGRA {
+            return Ok(());
+        }
+
+        let width = self.width() as usize;
+
+        let stride = self.stride();
+
+        // When compiling to WASM, the buffer returned by FPDFBitmap_GetBuffer() is a copy
+        // that cannot be mutated in place, so we premultiply a copy of the buffer on all
+        // targets and apply it using FPDFBitmap_SetBuffer().
+
+        let mut buffer = self.as_bytes().to_owned();
+
+        for row in buffer.chunks_exact_mut(stride) {
+            for pixel in row[..width * 4].chunks_exact_mut(4) {
+                // The alpha channel is always the last byte of each pixel, irrespective
+                // of whether the color channels are in BGR or RGB order.
+
+                let alpha = pixel[3] as u16;
+
+                if alpha < 255 {
+                    for channel in pixel[..3].iter_mut() {
+                        *channel = ((*channel as u16 * alpha + 127) / 255) as u8;
+                    }
+                }
+            }
+        }
+
+        if self.bindings.FPDFBitmap_SetBuffer(self.handle, &buffer) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing this [PdfBitmap].
     ///
+    /// The type of the returned image depends on the pixel format of this bitmap:
+    /// * [PdfBitmapFormat::Gray] bitmaps are returned as `DynamicImage::ImageLuma8`.
+    /// * [PdfBitmapFormat::BGR] bitmaps are returned as `DynamicImage::ImageRgb8`.
+    /// * [PdfBitmapFormat::BRGx] and [PdfBitmapFormat::BGRA] bitmaps are returned as
+    ///   `DynamicImage::ImageRgba8`. The unused fourth byte of each [PdfBitmapFormat::BRGx]
+    ///   pixel is set to fully opaque.
+    ///
+    /// Any padding at the end of each row of the bitmap buffer is removed. Color channels
+    /// are copied in the order they appear in the bitmap buffer, so the image will only be
+    /// in RGB order if the bitmap was rendered with `PdfRenderConfig::set_reverse_byte_order()`
+    /// enabled, as it is by default.
+    ///
     /// This function is only available when this crate's `image` feature is enabled.
     #[cfg(feature = "image")]
     pub fn as_image(&self) -> DynamicImage {
-        ImageBuffer::from_raw(
-            self.width() as u32,
-            self.height() as u32,
-            self.as_bytes().to_owned(),
-        )
-        .map(DynamicImage::ImageRgba8)
+        let width = self.width() as u32;
+
+        let height = self.height() as u32;
+
+        let format = self.format().unwrap_or(PdfBitmapFormat::BGRA);
+
+        let bytes_per_pixel = match format {
+            PdfBitmapFormat::Gray => 1,
+            PdfBitmapFormat::BGR => 3,
+            PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRA => 4,
+        };
+
+        let row_length = width as usize * bytes_per_pixel;
+
+        let stride = self.stride();
+
+        let mut bytes = if stride == row_length {
+            self.as_bytes().to_owned()
+        } else {
+            self.as_bytes()
+                .chunks(stride)
+                .flat_map(|row| &row[..row_length])
+                .copied()
+                .collect()
+        };
+
+        match format {
+            PdfBitmapFormat::Gray => {
+                ImageBuffer::from_raw(width, height, bytes).map(DynamicImage::ImageLuma8)
+            }
+            PdfBitmapFormat::BGR => {
+                ImageBuffer::from_raw(width, height, bytes).map(DynamicImage::ImageRgb8)
+            }
+            PdfBitmapFormat::BRGx => {
+                for pixel in bytes.chunks_exact_mut(4) {
+                    pixel[3] = 255;
+                }
+
+                ImageBuffer::from_raw(width, height, bytes).map(DynamicImage::ImageRgba8)
+            }
+            PdfBitmapFormat::BGRA => {
+                ImageBuffer::from_raw(width, height, bytes).map(DynamicImage::ImageRgba8)
+            }
+        }
         .unwrap()
     }
 
+    /// Returns a new [PdfBitmap] containing the image in this [PdfBitmap] resampled to the
+    /// given pixel width and height using the given `image::imageops::FilterType`.
+    ///
+    /// This allows a page to be rendered once at a high resolution and then scaled down to
+    /// several smaller sizes without re-rendering. Resampling filters such as
+    /// `FilterType::Lanczos3` or `FilterType::CatmullRom` typically produce noticeably sharper
+    /// results than Pdfium's internal scaling. The returned bitmap has the same pixel format
+    /// as this bitmap; any alpha channel is resampled along with the color channels.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn resized(
+        &self,
+        width: Pixels,
+        height: Pixels,
+        filter: FilterType,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let format = self.format()?;
+
+        let bytes_per_pixel = match format {
+            PdfBitmapFormat::Gray => 1,
+            PdfBitmapFormat::BGR => 3,
+            PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRA => 4,
+        };
+
+        // Rows in Pdfium's bitmap buffers may be padded beyond the width of the image,
+        // so we repack the source rows contiguously before resampling.
+
+        let source_width = self.width() as usize;
+
+        let source_stride = self.stride();
+
+        let source = self
+            .as_bytes()
+            .chunks(source_stride)
+            .flat_map(|row| &row[..source_width * bytes_per_pixel])
+            .copied()
+            .collect::<Vec<_>>();
+
+        // Resampling is independent of channel order, so there is no need to convert
+        // Pdfium's BGR channel ordering to RGB and back again.
+
+        let (source_width, source_height) = (self.width() as u32, self.height() as u32);
+
+        let resampled = match bytes_per_pixel {
+            1 => GrayImage::from_raw(source_width, source_height, source)
+                .map(|image| resize(&image, width as u32, height as u32, filter).into_raw()),
+            3 => RgbImage::from_raw(source_width, source_height, source)
+                .map(|image| resize(&image, width as u32, height as u32, filter).into_raw()),
+            _ => RgbaImage::from_raw(source_width, source_height, source)
+                .map(|image| resize(&image, width as u32, height as u32, filter).into_raw()),
+        }
+        .ok_or(PdfiumError::ImageError)?;
+
+        let result = PdfBitmap::empty(width, height, format, self.bindings)?;
+
+        let target_stride = result.stride();
+
+        let mut buffer = vec![0; target_stride * height as usize];
+
+        for (row, resampled_row) in buffer
+            .chunks_mut(target_stride)
+            .zip(resampled.chunks(width as usize * bytes_per_pixel))
+        {
+            row[..resampled_row.len()].copy_from_slice(resampled_row);
+        }
+
+        if self.bindings.FPDFBitmap_SetBuffer(result.handle, &buffer) {
+            Ok(result)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Encodes the image in this [PdfBitmap] in PNG format, streaming the encoded bytes
+    /// directly to the given writer.
+    ///
+    /// Where possible, the bitmap buffer backing this [PdfBitmap] is passed straight to the
+    /// PNG encoder, avoiding both the intermediate copy made by [PdfBitmap::as_image()] and
+    /// any intermediate buffer of encoded bytes.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn write_png<W: Write>(&self, writer: &mut W) -> Result<(), PdfiumError> {
+        let width = self.width() as u32;
+
+        let height = self.height() as u32;
+
+        let encoder = PngEncoder::new(writer);
+
+        let result = match self.format()? {
+            PdfBitmapFormat::BGRA if self.stride() as u32 == width * 4 => {
+                // The bitmap buffer is tightly packed, so it can be encoded in place.
+                // (The fourth byte of a BRGx pixel is undefined rather than an alpha value,
+                // so BRGx bitmaps must be converted by as_image() instead.)
+
+                encoder.write_image(self.as_bytes(), width, height, ColorType::Rgba8.into())
+            }
+            _ => {
+                let image = self.as_image();
+
+                encoder.write_image(image.as_bytes(), width, height, image.color().into())
+            }
+        };
+
+        result.map_err(|_| PdfiumError::ImageError)
+    }
+
+    /// Encodes the image in this [PdfBitmap] in PNG format, writing the result to the given
+    /// writer. The given horizontal and vertical resolutions, in dots per inch, are recorded
+    /// in the image's `pHYs` chunk so that the image prints at the correct physical size.
+    ///
+    /// Use the `PdfRenderConfig::resolve_output_dpi()` function to obtain the resolution
+    /// at which a page was rendered.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn write_png_with_dpi<W: Write>(
+        &self,
+        writer: &mut W,
+        horizontal_dpi: f32,
+        vertical_dpi: f32,
+    ) -> Result<(), PdfiumError> {
+        let mut png = Vec::new();
+
+        self.as_image()
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|_| PdfiumError::ImageError)?;
+
+        insert_png_physical_dimensions(&mut png, horizontal_dpi, vertical_dpi)?;
+
+        writer.write_all(&png).map_err(PdfiumError::IoError)
+    }
+
+    /// Encodes the image in this [PdfBitmap] in JPEG format at the given quality setting
+    /// (between 1 and 100), writing the result to the given writer. The given horizontal
+    /// and vertical resolutions, in dots per inch, are recorded in the image's JFIF header
+    /// so that the image prints at the correct physical size. JPEG images do not support
+    /// transparency, so any alpha channel is discarded.
+    ///
+    /// Use the `PdfRenderConfig::resolve_output_dpi()` function to obtain the resolution
+    /// at which a page was rendered.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn write_jpeg_with_dpi<W: Write>(
+        &self,
+        writer: &mut W,
+        horizontal_dpi: f32,
+        vertical_dpi: f32,
+        quality: u8,
+    ) -> Result<(), PdfiumError> {
+        let mut encoder = JpegEncoder::new_with_quality(writer, quality);
+
+        encoder.set_pixel_density(PixelDensity {
+            density: (
+                horizontal_dpi.round().clamp(1.0, u16::MAX as f32) as u16,
+                vertical_dpi.round().clamp(1.0, u16::MAX as f32) as u16,
+            ),
+            unit: PixelDensityUnit::Inches,
+        });
+
+        encoder
+            .encode_image(&self.as_image().to_rgb8())
+            .map_err(|_| PdfiumError::ImageError)
+    }
+
     // TODO: AJRC - 29/7/22 - remove deprecated PdfBitmap::render() function in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Prior to 0.7.12, this function rendered the referenced page into a bitmap buffer.
@@ -308,6 +615,75 @@ impl<'a> PdfBitmap<'a> {
     }
 }
 
+/// Inserts a `pHYs` chunk recording the given horizontal and vertical resolutions, in dots
+/// per inch, into the given PNG-encoded image.
+///
+/// The `pHYs` chunk must appear before the first `IDAT` chunk, so the existing chunks are
+/// walked to find the first `IDAT` chunk and the new chunk is inserted immediately before it.
+#[cfg(feature = "image")]
+fn insert_png_physical_dimensions(
+    png: &mut Vec<u8>,
+    horizontal_dpi: f32,
+    vertical_dpi: f32,
+) -> Result<(), PdfiumError> {
+    const PNG_SIGNATURE_LENGTH: usize = 8;
+
+    const INCHES_PER_METER: f32 = 39.370_08;
+
+    let mut offset = PNG_SIGNATURE_LENGTH;
+
+    loop {
+        let header = png.get(offset..offset + 8).ok_or(PdfiumError::ImageError)?;
+
+        if &header[4..] == b"IDAT" {
+            break;
+        }
+
+        let data_length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+
+        // Each chunk comprises a 4-byte length, a 4-byte chunk type, the chunk data,
+        // and a 4-byte CRC.
+
+        offset += 12 + data_length as usize;
+    }
+
+    let mut chunk = Vec::with_capacity(21);
+
+    chunk.extend_from_slice(&9_u32.to_be_bytes());
+    chunk.extend_from_slice(b"pHYs");
+    chunk.extend_from_slice(&((horizontal_dpi * INCHES_PER_METER).round() as u32).to_be_bytes());
+    chunk.extend_from_slice(&((vertical_dpi * INCHES_PER_METER).round() as u32).to_be_bytes());
+    chunk.push(1); // Unit specifier: pixels per meter.
+
+    let crc = crc32(&chunk[4..]);
+
+    chunk.extend_from_slice(&crc.to_be_bytes());
+
+    png.splice(offset..offset, chunk);
+
+    Ok(())
+}
+
+/// Computes the CRC-32 checksum of the given bytes, as required for PNG chunks.
+#[cfg(feature = "image")]
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+
+    for byte in bytes {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
 impl<'a> Drop for PdfBitmap<'a> {
     /// Closes this [PdfBitmap], releasing the memory held by the bitmap buffer.
     #[inline]
@@ -315,3 +691,126 @@ impl<'a> Drop for PdfBitmap<'a> {
         self.bindings.FPDFBitmap_Destroy(self.handle);
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "image")]
+mod tests {
+    use super::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use image::{ImageBuffer, Rgba};
+    use std::convert::TryInto;
+
+    /// Splits the given PNG-encoded image into its chunks, returning the chunk type,
+    /// chunk data, and stored CRC of each chunk.
+    fn png_chunks(png: &[u8]) -> Vec<([u8; 4], &[u8], u32)> {
+        let mut chunks = Vec::new();
+
+        let mut offset = 8;
+
+        while offset < png.len() {
+            let length = u32::from_be_bytes(png[offset..offset + 4].try_into().unwrap()) as usize;
+
+            let chunk_type = png[offset + 4..offset + 8].try_into().unwrap();
+
+            let data = &png[offset + 8..offset + 8 + length];
+
+            let crc = u32::from_be_bytes(
+                png[offset + 8 + length..offset + 12 + length]
+                    .try_into()
+                    .unwrap(),
+            );
+
+            chunks.push((chunk_type, data, crc));
+
+            offset += 12 + length;
+        }
+
+        chunks
+    }
+
+    #[test]
+    fn test_crc32() {
+        // The standard CRC-32 check value.
+
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_insert_png_physical_dimensions() {
+        let image =
+            DynamicImage::ImageRgba8(ImageBuffer::from_pixel(4, 3, Rgba([10_u8, 20, 30, 255])));
+
+        let mut png = Vec::new();
+
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        insert_png_physical_dimensions(&mut png, 300.0, 150.0).unwrap();
+
+        let chunks = png_chunks(&png);
+
+        // Every chunk, including those written by the image encoder, must have a valid CRC.
+
+        for (chunk_type, data, crc) in chunks.iter() {
+            let mut bytes = chunk_type.to_vec();
+
+            bytes.extend_from_slice(data);
+
+            assert_eq!(crc32(&bytes), *crc);
+        }
+
+        let phys = chunks
+            .iter()
+            .position(|(chunk_type, _, _)| chunk_type == b"pHYs")
+            .unwrap();
+
+        let idat = chunks
+            .iter()
+            .position(|(chunk_type, _, _)| chunk_type == b"IDAT")
+            .unwrap();
+
+        assert!(phys < idat);
+
+        let (_, data, _) = chunks[phys];
+
+        assert_eq!(u32::from_be_bytes(data[0..4].try_into().unwrap()), 11811); // 300 DPI
+        assert_eq!(u32::from_be_bytes(data[4..8].try_into().unwrap()), 5906); // 150 DPI
+        assert_eq!(data[8], 1);
+
+        // The image must still decode to the original pixels.
+
+        let decoded = image::load_from_memory(&png).unwrap();
+
+        assert_eq!(decoded.to_rgba8(), image.to_rgba8());
+    }
+
+    #[test]
+    fn test_resized() -> Result<(), PdfiumError> {
+        // A width of 41 pixels gives BGR rows that are padded beyond the width of the image.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let bitmap = PdfBitmap::empty(41, 30, PdfBitmapFormat::BGR, pdfium.bindings())?;
+
+        pdfium
+            .bindings()
+            .FPDFBitmap_FillRect(*bitmap.handle(), 0, 0, 41, 30, 0xFF102030);
+
+        let resized = bitmap.resized(20, 15, FilterType::Nearest)?;
+
+        assert_eq!(resized.width(), 20);
+        assert_eq!(resized.height(), 15);
+        assert_eq!(resized.format()?, PdfBitmapFormat::BGR);
+
+        // Every pixel retains the fill color, stored in BGR order.
+
+        for row in resized.as_bytes().chunks(resized.stride()) {
+            for pixel in row[..20 * 3].chunks(3) {
+                assert_eq!(pixel, [0x30, 0x20, 0x10]);
+            }
+        }
+
+        Ok(())
+    }
+}
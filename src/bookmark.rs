@@ -2,9 +2,10 @@
 //! in a `PdfBookmarks` collection.
 
 use crate::action::PdfAction;
-use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT};
+use crate::bindgen::{FPDF_BOOKMARK, FPDF_DEST, FPDF_DOCUMENT, PDFACTION_GOTO};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::bookmarks::PdfBookmarksIterator;
+use crate::pages::PdfPageIndex;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::os::raw::c_void;
@@ -92,6 +93,46 @@ impl<'a> PdfBookmark<'a> {
         }
     }
 
+    /// Returns the zero-based index of the page in the containing `PdfDocument` that this
+    /// [PdfBookmark] navigates to, if any.
+    ///
+    /// The bookmark's own destination is tried first. If the bookmark has no destination,
+    /// or its destination cannot be resolved to a page, then the destination of the bookmark's
+    /// `PdfActionType::GoToDestinationInSameDocument` action, if any, is tried instead.
+    /// Destinations given by name are resolved through the document's named destinations
+    /// in both cases. `None` is returned only if none of these resolve to a page in the document.
+    pub fn target_page_index(&self) -> Option<PdfPageIndex> {
+        self.page_index_for_dest(
+            self.bindings
+                .FPDFBookmark_GetDest(self.document, self.handle),
+        )
+        .or_else(|| {
+            let action = self.bindings.FPDFBookmark_GetAction(self.handle);
+
+            if action.is_null() || self.bindings.FPDFAction_GetType(action) as u32 != PDFACTION_GOTO
+            {
+                None
+            } else {
+                self.page_index_for_dest(self.bindings.FPDFAction_GetDest(self.document, action))
+            }
+        })
+    }
+
+    /// Resolves the given destination handle to a page index, if possible.
+    fn page_index_for_dest(&self, dest: FPDF_DEST) -> Option<PdfPageIndex> {
+        if dest.is_null() {
+            return None;
+        }
+
+        let index = self.bindings.FPDFDest_GetDestPageIndex(self.document, dest);
+
+        if index < 0 {
+            None
+        } else {
+            Some(index as PdfPageIndex)
+        }
+    }
+
     /// Returns this [PdfBookmark] object's direct parent, if available.
     #[inline]
     pub fn parent(&self) -> Option<PdfBookmark<'a>> {
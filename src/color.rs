@@ -4,7 +4,8 @@ use crate::bindgen::FPDF_DWORD;
 
 /// A 32-bit RGB color value with an optional alpha channel.
 ///
-/// Certain basic primary colors are available as const values on this struct.
+/// Certain basic primary colors, and a small palette of colors for print work,
+/// are available as const values on this struct.
 ///
 /// Note that when used as a form field highlight color, a solid color with no opacity
 /// will overprint any user data in the field. Use the [PdfColor::with_alpha()] function
@@ -25,6 +26,16 @@ impl PdfColor {
     pub const SOLID_MAGENTA: PdfColor = PdfColor::new(255, 0, 255, 255);
     pub const SOLID_CYAN: PdfColor = PdfColor::new(0, 255, 255, 255);
     pub const SOLID_YELLOW: PdfColor = PdfColor::new(255, 255, 0, 255);
+    pub const SOLID_BLACK: PdfColor = PdfColor::new(0, 0, 0, 255);
+
+    /// The RGB equivalent of process cyan ink.
+    pub const PROCESS_CYAN: PdfColor = PdfColor::new(0, 174, 239, 255);
+
+    /// The RGB equivalent of process magenta ink.
+    pub const PROCESS_MAGENTA: PdfColor = PdfColor::new(236, 0, 140, 255);
+
+    /// The RGB equivalent of process yellow ink.
+    pub const PROCESS_YELLOW: PdfColor = PdfColor::new(255, 242, 0, 255);
 
     #[inline]
     // The from_pdfium() function is not currently used, but we expect it to be in future
@@ -58,6 +69,16 @@ impl PdfColor {
         (self.color(), self.alpha() as u8)
     }
 
+    /// Returns this color, including its alpha value, encoded as a 32-bit value in
+    /// 0xAARRGGBB format, suitable for passing to Pdfium functions expecting ARGB colors.
+    #[inline]
+    pub(crate) fn as_pdfium_argb_color(&self) -> FPDF_DWORD {
+        ((self.a as FPDF_DWORD) << 24)
+            | ((self.r as FPDF_DWORD) << 16)
+            | ((self.g as FPDF_DWORD) << 8)
+            | (self.b as FPDF_DWORD)
+    }
+
     /// Constructs a new [PdfColor] object from the given arguments.
     #[inline]
     pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
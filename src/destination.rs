@@ -1,13 +1,17 @@
 //! Defines the [PdfDestination] struct, exposing functionality related to the target destination
 //! of a link contained within a single `PdfPage`.
 
-use crate::bindgen::FPDF_DEST;
+use crate::bindgen::{FPDF_BOOL, FPDF_DEST, FPDF_DOCUMENT, FS_FLOAT};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::page::PdfPoints;
+use crate::pages::PdfPageIndex;
 
 /// The page and region, if any, that will be the target of any behaviour that will occur
 /// when the user interacts with a link in a PDF viewer.
 pub struct PdfDestination<'a> {
     handle: FPDF_DEST,
+    document: FPDF_DOCUMENT,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -15,8 +19,16 @@ impl<'a> PdfDestination<'a> {
     // TODO: AJRC - 18/2/23 - as the PdfDestination struct is fleshed out, the example at
     // examples/links.rs should be expanded to demonstrate the new functionality.
 
-    pub(crate) fn from_pdfium(handle: FPDF_DEST, bindings: &'a dyn PdfiumLibraryBindings) -> Self {
-        PdfDestination { handle, bindings }
+    pub(crate) fn from_pdfium(
+        handle: FPDF_DEST,
+        document: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfDestination {
+            handle,
+            document,
+            bindings,
+        }
     }
 
     /// Returns the internal `FPDF_DEST` handle for this [PdfDestination].
@@ -31,4 +43,78 @@ impl<'a> PdfDestination<'a> {
     pub fn bindings(&self) -> &dyn PdfiumLibraryBindings {
         self.bindings
     }
+
+    /// Returns the zero-based index of the target page of this [PdfDestination].
+    pub fn page_index(&self) -> Result<PdfPageIndex, PdfiumError> {
+        let index = self
+            .bindings
+            .FPDFDest_GetDestPageIndex(self.document, self.handle);
+
+        if index < 0 {
+            Err(PdfiumError::DestinationPageIndexNotAvailable)
+        } else {
+            Ok(index as PdfPageIndex)
+        }
+    }
+
+    /// Returns the horizontal position of the target region of this [PdfDestination],
+    /// measured from the left edge of the target page, if specified.
+    #[inline]
+    pub fn x(&self) -> Option<PdfPoints> {
+        self.location().and_then(|(x, _, _)| x)
+    }
+
+    /// Returns the vertical position of the target region of this [PdfDestination],
+    /// measured from the bottom edge of the target page, if specified.
+    #[inline]
+    pub fn y(&self) -> Option<PdfPoints> {
+        self.location().and_then(|(_, y, _)| y)
+    }
+
+    /// Returns the zoom factor that a PDF viewer should apply when navigating to this
+    /// [PdfDestination], if specified. A zoom factor of `1.0` indicates 100% magnification.
+    #[inline]
+    pub fn zoom(&self) -> Option<f32> {
+        self.location().and_then(|(_, _, zoom)| zoom)
+    }
+
+    /// Returns the horizontal position, vertical position, and zoom factor of the target
+    /// region of this [PdfDestination]. Each of these is optional; Pdfium only reports
+    /// values for destinations that specify them explicitly.
+    fn location(&self) -> Option<(Option<PdfPoints>, Option<PdfPoints>, Option<f32>)> {
+        let mut has_x: FPDF_BOOL = 0;
+
+        let mut has_y: FPDF_BOOL = 0;
+
+        let mut has_zoom: FPDF_BOOL = 0;
+
+        let mut x: FS_FLOAT = 0.0;
+
+        let mut y: FS_FLOAT = 0.0;
+
+        let mut zoom: FS_FLOAT = 0.0;
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFDest_GetLocationInPage(
+                self.handle,
+                &mut has_x,
+                &mut has_y,
+                &mut has_zoom,
+                &mut x,
+                &mut y,
+                &mut zoom,
+            ))
+        {
+            Some((
+                self.bindings.is_true(has_x).then(|| PdfPoints::new(x)),
+                self.bindings.is_true(has_y).then(|| PdfPoints::new(y)),
+                // A zoom factor of zero indicates that the viewer should retain its
+                // current zoom level.
+                (self.bindings.is_true(has_zoom) && zoom != 0.0).then_some(zoom),
+            ))
+        } else {
+            None
+        }
+    }
 }
@@ -2,18 +2,23 @@
 //! related to a single PDF file.
 
 use crate::attachments::PdfAttachments;
-use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_DWORD, FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::bookmarks::PdfBookmarks;
+use crate::document_annotations::PdfDocumentAnnotationsIterator;
+use crate::document_diff::{PdfDocumentDiff, PdfDocumentDiffGranularity};
 use crate::error::PdfiumError;
 use crate::error::PdfiumInternalError;
 use crate::form::PdfForm;
 use crate::metadata::PdfMetadata;
+use crate::page_cache::PdfPageCache;
+use crate::page_objects_common::{PdfPageObjectIndex, PdfPageObjectsCommon};
 use crate::pages::PdfPages;
-use crate::permissions::PdfPermissions;
+use crate::permissions::{PdfPermissions, PdfSecurityHandlerRevision};
 use crate::signatures::PdfSignatures;
 use crate::utils::files::get_pdfium_file_writer_from_writer;
 use crate::utils::files::FpdfFileAccessExt;
+use std::cell::RefCell;
 use std::io::Cursor;
 use std::io::Write;
 
@@ -23,6 +28,12 @@ use std::fs::File;
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::pages::PdfPageIndex;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::ops::RangeInclusive;
+
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Uint8Array};
 
@@ -137,12 +148,15 @@ impl PdfDocumentVersion {
 pub struct PdfDocument<'a> {
     handle: FPDF_DOCUMENT,
     output_version: Option<PdfDocumentVersion>,
+    do_force_classic_xref: bool,
+    do_save_incrementally: bool,
     attachments: PdfAttachments<'a>,
     bookmarks: PdfBookmarks<'a>,
     form: Option<PdfForm<'a>>,
     metadata: PdfMetadata<'a>,
     permissions: PdfPermissions<'a>,
     signatures: PdfSignatures<'a>,
+    page_cache: RefCell<PdfPageCache>,
     bindings: &'a dyn PdfiumLibraryBindings,
     source_byte_buffer: Option<Vec<u8>>,
 
@@ -160,12 +174,15 @@ impl<'a> PdfDocument<'a> {
         Self {
             handle,
             output_version: None,
+            do_force_classic_xref: false,
+            do_save_incrementally: false,
             attachments: PdfAttachments::from_pdfium(handle, bindings),
             bookmarks: PdfBookmarks::from_pdfium(handle, bindings),
             form: PdfForm::from_pdfium(handle, bindings),
             metadata: PdfMetadata::from_pdfium(handle, bindings),
             permissions: PdfPermissions::from_pdfium(handle, bindings),
             signatures: PdfSignatures::from_pdfium(handle, bindings),
+            page_cache: RefCell::new(PdfPageCache::new()),
             bindings,
             source_byte_buffer: None,
             file_access_reader: None,
@@ -184,6 +201,22 @@ impl<'a> PdfDocument<'a> {
         self.bindings
     }
 
+    /// Returns the cache of loaded pages for this [PdfDocument].
+    #[inline]
+    pub(crate) fn page_cache(&self) -> &RefCell<PdfPageCache> {
+        &self.page_cache
+    }
+
+    /// Evicts all pages from the cache of loaded pages for this [PdfDocument]. This must be
+    /// called whenever pages are inserted into or deleted from this [PdfDocument], since
+    /// cached pages are keyed by the index at which they were loaded.
+    #[inline]
+    pub(crate) fn invalidate_page_cache(&self) {
+        self.page_cache
+            .borrow_mut()
+            .invalidate(self.handle, self.bindings);
+    }
+
     /// Binds a byte buffer to the lifetime of this [PdfDocument], so that it will always be
     /// available for Pdfium to read data from as needed.
     #[inline]
@@ -216,6 +249,35 @@ impl<'a> PdfDocument<'a> {
         self.output_version = Some(version);
     }
 
+    /// Controls whether the next save of this [PdfDocument] should avoid cross-reference streams
+    /// and object streams, writing a classic cross-reference table instead. This improves
+    /// compatibility with older PDF consumers that cannot parse the compressed cross-reference
+    /// structures introduced in PDF 1.5.
+    ///
+    /// When enabled, the document will be saved as PDF 1.4 unless an earlier file version
+    /// has been set using [PdfDocument::set_version()]. Saving will fail with
+    /// [PdfiumError::ClassicXrefRequiresLegacyVersion] if a later file version has been set,
+    /// or if the document is protected by a security handler that requires PDF 1.5 or later.
+    pub fn set_force_classic_xref(&mut self, do_force_classic_xref: bool) {
+        self.do_force_classic_xref = do_force_classic_xref;
+    }
+
+    /// Controls whether the next save of this [PdfDocument] should be an incremental save.
+    ///
+    /// An incremental save writes the original, unmodified document data followed by
+    /// an update section containing only the changes made since the document was loaded.
+    /// This preserves any digital signatures over the original data and is typically faster
+    /// for small edits to large documents. A full rewrite, the default, writes a new copy
+    /// of the entire document, discarding unused objects.
+    ///
+    /// Pdfium falls back to a full rewrite if the document was created in memory rather than
+    /// loaded from an existing source. Saving will fail with
+    /// [PdfiumError::IncrementalSaveIncompatibleWithClassicXref] if incremental saving is
+    /// enabled at the same time as [PdfDocument::set_force_classic_xref()].
+    pub fn set_save_incrementally(&mut self, do_save_incrementally: bool) {
+        self.do_save_incrementally = do_save_incrementally;
+    }
+
     /// Returns an immutable collection of all the [PdfAttachments] embedded in this [PdfDocument].
     #[inline]
     pub fn attachments(&self) -> &PdfAttachments {
@@ -246,6 +308,17 @@ impl<'a> PdfDocument<'a> {
         &self.metadata
     }
 
+    /// Returns the value associated with the given key in the document information dictionary
+    /// of this [PdfDocument], if any.
+    ///
+    /// Unlike the [PdfMetadata] collection returned by [PdfDocument::metadata()], which only
+    /// includes the standard metadata tags, any key may be requested, including custom
+    /// or vendor-specific keys. Returns `None` if the key is not present.
+    #[inline]
+    pub fn metadata_value(&self, key: &str) -> Option<String> {
+        self.metadata.get_raw_metadata_tag(key)
+    }
+
     /// Returns a collection of all the [PdfPages] in this [PdfDocument].
     // TODO: AJRC - 26/9/22 - distinguish between immutable and mutable access to PdfPages.
     // Tracking issue: https://github.com/ajrcarey/pdfium-render/issues/47.
@@ -254,6 +327,55 @@ impl<'a> PdfDocument<'a> {
         PdfPages::new(self)
     }
 
+    /// Returns an iterator over the annotations on every page of this [PdfDocument],
+    /// in page order.
+    ///
+    /// Each page is loaded only when the iterator reaches it, and is closed again once the
+    /// iterator has moved on and every annotation returned from the page has been dropped,
+    /// so annotations can be exported from large documents without holding every page in
+    /// memory at once. A page that fails to load is skipped, and a warning is logged.
+    #[inline]
+    pub fn annotations(&'a self) -> PdfDocumentAnnotationsIterator<'a> {
+        PdfDocumentAnnotationsIterator::new(self.pages())
+    }
+
+    /// Compares this [PdfDocument] with the given document, returning a [PdfDocumentDiff]
+    /// describing any differences in page count, page sizes, and (depending on the given
+    /// [PdfDocumentDiffGranularity]) the text extracted from each page.
+    ///
+    /// This is useful for confirming that a transformation applied to a document,
+    /// such as flattening form fields, did not unexpectedly alter its content.
+    #[inline]
+    pub fn diff<'b>(
+        &'a self,
+        other: &'b PdfDocument<'b>,
+        granularity: PdfDocumentDiffGranularity,
+    ) -> Result<PdfDocumentDiff, PdfiumError> {
+        PdfDocumentDiff::new(self, other, granularity)
+    }
+
+    /// Returns the number of page objects on each page in this [PdfDocument], in page order.
+    ///
+    /// Only top-level page objects are counted; the objects inside a form XObject are counted
+    /// as a single object. Each page is loaded in turn in order to count its objects.
+    #[inline]
+    pub fn page_object_counts(&'a self) -> Vec<PdfPageObjectIndex> {
+        self.pages()
+            .iter()
+            .map(|page| page.objects().len())
+            .collect()
+    }
+
+    /// Returns the total number of page objects across all pages in this [PdfDocument].
+    ///
+    /// This is a quick estimate of the complexity of the document, useful for example
+    /// when deciding on a time budget for rendering it. To retrieve the count for each page
+    /// individually, use the [PdfDocument::page_object_counts()] function.
+    #[inline]
+    pub fn page_object_count_total(&'a self) -> PdfPageObjectIndex {
+        self.page_object_counts().into_iter().sum()
+    }
+
     /// Returns an immutable collection of all the [PdfPermissions] applied to this [PdfDocument].
     #[inline]
     pub fn permissions(&self) -> &PdfPermissions {
@@ -266,18 +388,67 @@ impl<'a> PdfDocument<'a> {
         &self.signatures
     }
 
+    /// Closes this [PdfDocument], releasing held memory and, if the document was loaded
+    /// from a file, the file handle on the document.
+    ///
+    /// This is equivalent to dropping the [PdfDocument]. Since this function takes ownership
+    /// of the document, the borrow checker guarantees that the document cannot be closed twice,
+    /// and that no pages, forms, or other objects borrowed from the document are still alive
+    /// when it is closed.
+    #[inline]
+    pub fn close(self) {
+        // Dropping self closes the document.
+    }
+
     /// Writes this [PdfDocument] to the given writer.
+    ///
+    /// The document is written as a full rewrite unless incremental saving has been enabled
+    /// using [PdfDocument::set_save_incrementally()]. If the writer returns an error,
+    /// the save is abandoned and the error is returned as [PdfiumError::IoError].
     pub fn save_to_writer<W: Write + 'static>(&self, writer: &mut W) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
-        // and FPDF_REMOVE_SECURITY flags defined in fpdf_save.h. There's not a lot of information
-        // on what they actually do, however.
+        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_REMOVE_SECURITY flag defined
+        // in fpdf_save.h. There's not a lot of information on what it actually does, however.
         // Some small info at https://forum.patagames.com/posts/t155-PDF-SaveFlags.
 
-        let flags = 0;
+        if self.do_save_incrementally && self.do_force_classic_xref {
+            return Err(PdfiumError::IncrementalSaveIncompatibleWithClassicXref);
+        }
+
+        let (flags, output_version) = if self.do_force_classic_xref {
+            // Cross-reference streams and object streams were introduced in PDF 1.5.
+            // Saving a non-incremental copy at version 1.4 or earlier ensures Pdfium
+            // writes a classic cross-reference table.
+
+            let version = match self.output_version {
+                Some(version) if version != PdfDocumentVersion::Unset => version,
+                _ => PdfDocumentVersion::Pdf1_4,
+            };
+
+            if version.as_pdfium().unwrap() > 14
+                || matches!(
+                    self.permissions.security_handler_revision()?,
+                    PdfSecurityHandlerRevision::Revision4
+                        | PdfSecurityHandlerRevision::Revision5
+                        | PdfSecurityHandlerRevision::Revision6
+                )
+            {
+                return Err(PdfiumError::ClassicXrefRequiresLegacyVersion);
+            }
+
+            (FPDF_NO_INCREMENTAL as FPDF_DWORD, Some(version))
+        } else {
+            let flags = if self.do_save_incrementally {
+                FPDF_INCREMENTAL as FPDF_DWORD
+            } else {
+                0
+            };
+
+            (flags, self.output_version)
+        };
 
         let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(writer);
 
-        let result = match self.output_version {
+        let result = match output_version {
             Some(version) => self.bindings.FPDF_SaveWithVersion(
                 self.handle,
                 pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
@@ -293,6 +464,13 @@ impl<'a> PdfDocument<'a> {
             ),
         };
 
+        if let Some(err) = pdfium_file_writer.take_write_error() {
+            // The writer failed, so the output is incomplete regardless of
+            // Pdfium's return value.
+
+            return Err(PdfiumError::IoError(err));
+        }
+
         match self.bindings.is_true(result) {
             true => {
                 // Pdfium's return value indicated success. Flush the buffer.
@@ -324,6 +502,81 @@ impl<'a> PdfDocument<'a> {
         self.save_to_writer(&mut File::create(path).map_err(PdfiumError::IoError)?)
     }
 
+    /// Splits this [PdfDocument] into several new documents, one for each of the given
+    /// page ranges, writing each new document to the file at the path paired with its range.
+    ///
+    /// All ranges are validated before any files are written. An error is returned if any
+    /// range is empty or extends beyond the last page of this [PdfDocument], or if any two
+    /// ranges share a page and `allow_overlapping_ranges` is `false`.
+    ///
+    /// Each new document is saved using the file version of this [PdfDocument].
+    ///
+    /// Bookmarks are not copied into the new documents, not even those that point to pages
+    /// within the range being split out. Pdfium exposes no API for creating bookmarks, so
+    /// there is no way to recreate them in a new document.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn split_by_ranges<P: AsRef<Path>>(
+        &self,
+        ranges: &[(RangeInclusive<PdfPageIndex>, P)],
+        allow_overlapping_ranges: bool,
+    ) -> Result<(), PdfiumError> {
+        let page_count = self.bindings.FPDF_GetPageCount(self.handle) as PdfPageIndex;
+
+        if ranges
+            .iter()
+            .any(|(range, _)| range.is_empty() || *range.end() >= page_count)
+        {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        if !allow_overlapping_ranges {
+            let mut sorted = ranges.iter().map(|(range, _)| range).collect::<Vec<_>>();
+
+            sorted.sort_by_key(|range| *range.start());
+
+            if sorted
+                .windows(2)
+                .any(|pair| *pair[1].start() <= *pair[0].end())
+            {
+                return Err(PdfiumError::PageRangesOverlap);
+            }
+        }
+
+        let version = self.version();
+
+        for (range, path) in ranges.iter() {
+            let handle = self.bindings.FPDF_CreateNewDocument();
+
+            if handle.is_null() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ));
+            }
+
+            let mut document = PdfDocument::from_pdfium(handle, self.bindings);
+
+            if version != PdfDocumentVersion::Unset {
+                document.set_version(version);
+            }
+
+            PdfPages::copy_page_range_between_documents(
+                self.handle,
+                range.clone(),
+                handle,
+                0,
+                self.bindings,
+            )?;
+
+            document.save_to_file(path.as_ref())?;
+        }
+
+        Ok(())
+    }
+
     /// Writes this [PdfDocument] to a new byte buffer, returning the byte buffer.
     pub fn save_to_bytes(&self) -> Result<Vec<u8>, PdfiumError> {
         let mut cursor = Cursor::new(Vec::new());
@@ -357,6 +610,81 @@ impl<'a> Drop for PdfDocument<'a> {
     /// from a file, the file handle on the document.
     #[inline]
     fn drop(&mut self) {
+        // Struct fields are dropped only after this function returns. Any cached pages,
+        // and the embedded form, if any, hold resources bound to this document, so they must
+        // be released before the document itself is closed.
+
+        self.page_cache.get_mut().clear(self.handle, self.bindings);
+
+        self.form = None;
+
         self.bindings.FPDF_CloseDocument(self.handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_annotations() -> Result<(), PdfiumError> {
+        // Confirms that the annotations returned across the whole document match
+        // the annotations on each page, in page order.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotations-test.pdf", None)?;
+
+        let expected = document
+            .pages()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, page)| {
+                (0..page.annotations().len()).map(move |_| index as PdfPageIndex)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(!expected.is_empty());
+
+        let actual = document
+            .annotations()
+            .map(|annotation| annotation.page_index())
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_split_by_ranges() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let page_count = document.pages().len();
+
+        assert!(page_count > 2);
+
+        let directory = std::env::temp_dir();
+
+        let first = directory.join("pdfium-render-split-first.pdf");
+
+        let rest = directory.join("pdfium-render-split-rest.pdf");
+
+        document.split_by_ranges(&[(0..=1, &first), (2..=page_count - 1, &rest)], false)?;
+
+        assert_eq!(pdfium.load_pdf_from_file(&first, None)?.pages().len(), 2);
+        assert_eq!(
+            pdfium.load_pdf_from_file(&rest, None)?.pages().len(),
+            page_count - 2
+        );
+
+        std::fs::remove_file(first).map_err(PdfiumError::IoError)?;
+        std::fs::remove_file(rest).map_err(PdfiumError::IoError)?;
+
+        Ok(())
+    }
+}
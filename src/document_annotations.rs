@@ -0,0 +1,120 @@
+//! Defines the [PdfDocumentAnnotationsIterator] struct, an iterator over the annotations
+//! on every page of a `PdfDocument`.
+
+use crate::error::PdfiumError;
+use crate::page::PdfPage;
+use crate::page_annotation::PdfPageAnnotation;
+use crate::page_annotations::PdfPageAnnotationIndex;
+use crate::pages::{PdfPageIndex, PdfPages};
+use std::rc::Rc;
+
+/// A single annotation on a page of a `PdfDocument`, as returned by a
+/// [PdfDocumentAnnotationsIterator].
+///
+/// Each [PdfDocumentAnnotation] shares ownership of the [PdfPage] containing the annotation,
+/// keeping the page loaded for as long as it is needed. The page is closed once every
+/// [PdfDocumentAnnotation] on it has been dropped and the iterator has moved on to the next page.
+pub struct PdfDocumentAnnotation<'a> {
+    page_index: PdfPageIndex,
+    page: Rc<PdfPage<'a>>,
+    annotation_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfDocumentAnnotation<'a> {
+    /// Returns the index of the [PdfPage] containing this annotation.
+    #[inline]
+    pub fn page_index(&self) -> PdfPageIndex {
+        self.page_index
+    }
+
+    /// Returns the [PdfPage] containing this annotation.
+    #[inline]
+    pub fn page(&self) -> &PdfPage<'a> {
+        &self.page
+    }
+
+    /// Returns the index of this annotation in the annotations collection of its containing page.
+    #[inline]
+    pub fn annotation_index(&self) -> PdfPageAnnotationIndex {
+        self.annotation_index
+    }
+
+    /// Returns the [PdfPageAnnotation] itself.
+    #[inline]
+    pub fn annotation(&self) -> Result<PdfPageAnnotation<'_>, PdfiumError> {
+        self.page.annotations().get(self.annotation_index)
+    }
+}
+
+/// An iterator over the annotations on every page of a `PdfDocument`, in page order,
+/// as returned by the `PdfDocument::annotations()` function.
+///
+/// Pages are loaded lazily, one at a time, as the iterator reaches them. Pages that
+/// cannot be loaded are skipped.
+pub struct PdfDocumentAnnotationsIterator<'a> {
+    pages: PdfPages<'a>,
+    next_page_index: PdfPageIndex,
+    current_page: Option<(PdfPageIndex, Rc<PdfPage<'a>>)>,
+    next_annotation_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfDocumentAnnotationsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(pages: PdfPages<'a>) -> Self {
+        PdfDocumentAnnotationsIterator {
+            pages,
+            next_page_index: 0,
+            current_page: None,
+            next_annotation_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfDocumentAnnotationsIterator<'a> {
+    type Item = PdfDocumentAnnotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((page_index, page)) = self.current_page.as_ref() {
+                if self.next_annotation_index < page.annotations().len() {
+                    let annotation_index = self.next_annotation_index;
+
+                    self.next_annotation_index += 1;
+
+                    return Some(PdfDocumentAnnotation {
+                        page_index: *page_index,
+                        page: page.clone(),
+                        annotation_index,
+                    });
+                }
+            }
+
+            // The current page has no more annotations. Release it and move on to the next page.
+
+            self.current_page = None;
+
+            if self.next_page_index >= self.pages.len() {
+                return None;
+            }
+
+            let page_index = self.next_page_index;
+
+            self.next_page_index += 1;
+
+            match self.pages.get(page_index) {
+                Ok(page) => {
+                    self.current_page = Some((page_index, Rc::new(page)));
+
+                    self.next_annotation_index = 0;
+                }
+                Err(error) => {
+                    log::warn!(
+                        "pdfium-render::PdfDocumentAnnotationsIterator: skipping page {} after error: {:?}",
+                        page_index,
+                        error
+                    );
+                }
+            }
+        }
+    }
+}
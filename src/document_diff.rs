@@ -0,0 +1,212 @@
+//! Defines the [PdfDocumentDiff] struct, a summary of the structural and textual differences
+//! between two `PdfDocument` objects.
+
+use crate::document::PdfDocument;
+use crate::error::PdfiumError;
+use crate::page::PdfPoints;
+use crate::pages::PdfPageIndex;
+
+/// The level of detail used when comparing two `PdfDocument` objects.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfDocumentDiffGranularity {
+    /// Compare only page counts and page sizes.
+    Structural,
+
+    /// Compare page counts and page sizes, as well as the text extracted from each page.
+    Text,
+}
+
+/// A single line of text that differs between the same page in two `PdfDocument` objects.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfDocumentDiffLine {
+    /// A line of text present on the page in the original document that is not present
+    /// on the page in the other document.
+    Removed(String),
+
+    /// A line of text present on the page in the other document that is not present
+    /// on the page in the original document.
+    Inserted(String),
+}
+
+/// The differences between a single page that exists in both of two compared
+/// `PdfDocument` objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPageDiff {
+    index: PdfPageIndex,
+    size: Option<((PdfPoints, PdfPoints), (PdfPoints, PdfPoints))>,
+    text: Vec<PdfDocumentDiffLine>,
+}
+
+impl PdfPageDiff {
+    /// Returns the zero-based index of the page that differs.
+    #[inline]
+    pub fn index(&self) -> PdfPageIndex {
+        self.index
+    }
+
+    /// Returns the width and height of the page in the original document, followed by
+    /// the width and height of the page in the other document, if the page sizes differ.
+    #[inline]
+    pub fn size(&self) -> Option<((PdfPoints, PdfPoints), (PdfPoints, PdfPoints))> {
+        self.size
+    }
+
+    /// Returns the lines of text that were removed from or inserted into the page,
+    /// in page order. The returned list will always be empty if the documents were compared
+    /// using [PdfDocumentDiffGranularity::Structural].
+    #[inline]
+    pub fn text(&self) -> &[PdfDocumentDiffLine] {
+        self.text.as_slice()
+    }
+}
+
+/// A summary of the differences between two `PdfDocument` objects, as returned by the
+/// `PdfDocument::diff()` function.
+///
+/// Only pages that exist in both documents are compared individually; any pages beyond the
+/// end of the shorter document are reflected only in the difference in page counts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfDocumentDiff {
+    page_counts: (PdfPageIndex, PdfPageIndex),
+    pages: Vec<PdfPageDiff>,
+}
+
+impl PdfDocumentDiff {
+    /// Compares the two given documents using the given [PdfDocumentDiffGranularity].
+    pub(crate) fn new<'a, 'b>(
+        document: &'a PdfDocument<'a>,
+        other: &'b PdfDocument<'b>,
+        granularity: PdfDocumentDiffGranularity,
+    ) -> Result<Self, PdfiumError> {
+        let pages = document.pages();
+
+        let other_pages = other.pages();
+
+        let mut result = Vec::new();
+
+        for index in 0..pages.len().min(other_pages.len()) {
+            let page = pages.get(index)?;
+
+            let other_page = other_pages.get(index)?;
+
+            let size = (page.width(), page.height());
+
+            let other_size = (other_page.width(), other_page.height());
+
+            let size = if size != other_size {
+                Some((size, other_size))
+            } else {
+                None
+            };
+
+            let text = if granularity == PdfDocumentDiffGranularity::Text {
+                diff_lines(&page.text()?.all(), &other_page.text()?.all())
+            } else {
+                Vec::new()
+            };
+
+            if size.is_some() || !text.is_empty() {
+                result.push(PdfPageDiff { index, size, text });
+            }
+        }
+
+        Ok(PdfDocumentDiff {
+            page_counts: (pages.len(), other_pages.len()),
+            pages: result,
+        })
+    }
+
+    /// Returns the number of pages in the original document, followed by the number of
+    /// pages in the other document.
+    #[inline]
+    pub fn page_counts(&self) -> (PdfPageIndex, PdfPageIndex) {
+        self.page_counts
+    }
+
+    /// Returns the differences for each page that exists in both documents but differs
+    /// between them. Pages that are identical are not included.
+    #[inline]
+    pub fn pages(&self) -> &[PdfPageDiff] {
+        self.pages.as_slice()
+    }
+
+    /// Returns `true` if no differences were found between the two documents at the
+    /// granularity used to compare them.
+    #[inline]
+    pub fn is_identical(&self) -> bool {
+        self.page_counts.0 == self.page_counts.1 && self.pages.is_empty()
+    }
+}
+
+/// Computes a simple line diff between the two given strings, based on the longest
+/// common subsequence of lines.
+fn diff_lines(text: &str, other: &str) -> Vec<PdfDocumentDiffLine> {
+    let lines = text.lines().collect::<Vec<_>>();
+
+    let other_lines = other.lines().collect::<Vec<_>>();
+
+    // lengths[i][j] holds the length of the longest common subsequence of
+    // lines[i..] and other_lines[j..].
+
+    let mut lengths = vec![vec![0usize; other_lines.len() + 1]; lines.len() + 1];
+
+    for i in (0..lines.len()).rev() {
+        for j in (0..other_lines.len()).rev() {
+            lengths[i][j] = if lines[i] == other_lines[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < lines.len() && j < other_lines.len() {
+        if lines[i] == other_lines[j] {
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            result.push(PdfDocumentDiffLine::Removed(lines[i].to_owned()));
+            i += 1;
+        } else {
+            result.push(PdfDocumentDiffLine::Inserted(other_lines[j].to_owned()));
+            j += 1;
+        }
+    }
+
+    result.extend(
+        lines[i..]
+            .iter()
+            .map(|line| PdfDocumentDiffLine::Removed((*line).to_owned())),
+    );
+
+    result.extend(
+        other_lines[j..]
+            .iter()
+            .map(|line| PdfDocumentDiffLine::Inserted((*line).to_owned())),
+    );
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        assert!(diff_lines("a\nb\nc", "a\nb\nc").is_empty());
+
+        assert_eq!(
+            diff_lines("a\nb\nc", "a\nx\nc\nd"),
+            vec![
+                PdfDocumentDiffLine::Removed("b".to_owned()),
+                PdfDocumentDiffLine::Inserted("x".to_owned()),
+                PdfDocumentDiffLine::Inserted("d".to_owned()),
+            ]
+        );
+    }
+}
@@ -65,6 +65,7 @@ pub enum PdfiumError {
     ImageObjectFilterIndexInBoundsButFilterUndefined,
     UnknownPdfColorSpace,
     InvalidTransformationMatrix,
+    InvalidDashPattern,
     SignatureIndexOutOfBounds,
     AttachmentIndexOutOfBounds,
     NoDataInAttachment,
@@ -79,6 +80,26 @@ pub enum PdfiumError {
     GroupContainsNonCloneablePageObjects,
     SourcePageIndexNotInCache,
     NoUriForAction,
+    NoFilePathForAction,
+    NoDestinationForAction,
+    DestinationPageIndexNotAvailable,
+    InkStrokeIndexOutOfBounds,
+    ClassicXrefRequiresLegacyVersion,
+    NoContentOnPage,
+    FormFieldOptionIndexOutOfBounds,
+    FormFieldNotTextField,
+    FormFieldReadOnly,
+    FormFieldValueContainsLineBreaks,
+    FormFieldValueExceedsMaxLength,
+    FormFieldNotCheckable,
+    RadioButtonCannotBeUnchecked,
+    PageRangesOverlap,
+    IncrementalSaveIncompatibleWithClassicXref,
+    AttachmentNameAlreadyExists,
+    RegionHasNoArea,
+    PageObjectNotHideable,
+    AnnotationOverlayTransformNotSupported,
+    NegativeMargin,
 
     /// An error occurred converting a byte stream into a CString.
     CStringConversionError(IntoStringError),
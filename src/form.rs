@@ -2,14 +2,29 @@
 //! embedded in a `PdfDocument`.
 
 use crate::bindgen::{
-    FORMTYPE_ACRO_FORM, FORMTYPE_NONE, FORMTYPE_XFA_FOREGROUND, FORMTYPE_XFA_FULL, FPDF_DOCUMENT,
-    FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX,
-    FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE,
-    FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMFILLINFO, FPDF_FORMHANDLE,
+    FORMTYPE_ACRO_FORM, FORMTYPE_NONE, FORMTYPE_XFA_FOREGROUND, FORMTYPE_XFA_FULL, FPDF_ANNOTATION,
+    FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_APPEARANCEMODE, FPDF_ANNOT_APPEARANCEMODE_NORMAL,
+    FPDF_ANNOT_WIDGET, FPDF_DOCUMENT, FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX,
+    FPDF_FORMFIELD_LISTBOX, FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON,
+    FPDF_FORMFIELD_SIGNATURE, FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMFILLINFO,
+    FPDF_FORMFLAG_CHOICE_COMBO, FPDF_FORMFLAG_CHOICE_EDIT, FPDF_FORMFLAG_CHOICE_MULTI_SELECT,
+    FPDF_FORMFLAG_NOEXPORT, FPDF_FORMFLAG_READONLY, FPDF_FORMFLAG_REQUIRED,
+    FPDF_FORMFLAG_TEXT_MULTILINE, FPDF_FORMFLAG_TEXT_PASSWORD, FPDF_FORMHANDLE, FPDF_PAGE,
+    FPDF_WCHAR, FS_RECTF,
 };
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page::{PdfPage, PdfPoints};
+use crate::page_annotation::{PdfPageAnnotation, PdfPageAnnotationType};
+use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::pages::PdfPageIndex;
+use crate::render_config::{PdfRenderConfig, PdfRenderSettings};
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use bitflags::bitflags;
+use std::collections::HashMap;
 use std::ops::DerefMut;
+use std::os::raw::{c_double, c_float, c_int, c_ulong};
 use std::pin::Pin;
 use std::ptr::null_mut;
 
@@ -50,6 +65,7 @@ impl PdfFormType {
 }
 
 /// The widget display type of a single form field in a [PdfForm].
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PdfFormFieldType {
     // The FPDF_FORMFIELD_COUNT constant simply specifies the number of form field
     // widget types supported by Pdfium; we do not need to expose it.
@@ -65,8 +81,6 @@ pub enum PdfFormFieldType {
 
 impl PdfFormFieldType {
     #[inline]
-    #[allow(dead_code)]
-    // The from_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn from_pdfium(form_field_type: u32) -> Result<PdfFormFieldType, PdfiumError> {
         match form_field_type {
             FPDF_FORMFIELD_UNKNOWN => Ok(PdfFormFieldType::Unknown),
@@ -82,8 +96,6 @@ impl PdfFormFieldType {
     }
 
     #[inline]
-    #[allow(dead_code)]
-    // The as_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn as_pdfium(&self) -> u32 {
         match self {
             PdfFormFieldType::Unknown => FPDF_FORMFIELD_UNKNOWN,
@@ -98,12 +110,198 @@ impl PdfFormFieldType {
     }
 }
 
+bitflags! {
+    /// The flags set on a single form field in a [PdfForm], as returned by the
+    /// [PdfForm::field_flags()] function.
+    ///
+    /// The `TEXT_*` flags apply only to text fields, and the `CHOICE_*` flags apply only
+    /// to combo boxes and list boxes.
+    pub struct PdfFormFieldFlags: u32 {
+        /// The user may not change the value of the field.
+        const READ_ONLY = FPDF_FORMFLAG_READONLY;
+
+        /// The field must have a value when the form is submitted.
+        const REQUIRED = FPDF_FORMFLAG_REQUIRED;
+
+        /// The field must not be exported when the form is submitted.
+        const NO_EXPORT = FPDF_FORMFLAG_NOEXPORT;
+
+        /// The text field may contain multiple lines of text.
+        const TEXT_MULTILINE = FPDF_FORMFLAG_TEXT_MULTILINE;
+
+        /// The text field is a password field whose value is not displayed.
+        const TEXT_PASSWORD = FPDF_FORMFLAG_TEXT_PASSWORD;
+
+        /// The text field does not scroll to accommodate more text than fits within its bounds.
+        const TEXT_DO_NOT_SCROLL = 0b00000000100000000000000000000000;
+
+        /// The text field is divided into as many equally spaced positions, or combs,
+        /// as its maximum length.
+        const TEXT_COMB = 0b00000001000000000000000000000000;
+
+        /// The choice field is a combo box rather than a list box.
+        const CHOICE_COMBO = FPDF_FORMFLAG_CHOICE_COMBO;
+
+        /// The combo box includes an editable text box as well as a drop-down list.
+        const CHOICE_EDIT = FPDF_FORMFLAG_CHOICE_EDIT;
+
+        /// The options of the choice field should be sorted alphabetically.
+        const CHOICE_SORT = 0b00000000000010000000000000000000;
+
+        /// More than one option of the choice field may be selected simultaneously.
+        const CHOICE_MULTI_SELECT = FPDF_FORMFLAG_CHOICE_MULTI_SELECT;
+    }
+}
+
+/// The type, name, value, and rendered position of a single form field widget
+/// on a `PdfPage`, as returned by the [PdfForm::field_layout()] function.
+///
+/// Pixel coordinates are measured from the top-left corner of a bitmap rendered from the
+/// page using the same `PdfRenderConfig` that was passed to [PdfForm::field_layout()],
+/// making it straightforward to position interactive controls over the rendered image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfFormFieldLayout {
+    field_type: PdfFormFieldType,
+    name: Option<String>,
+    value: Option<String>,
+    left: i32,
+    top: i32,
+    width: i32,
+    height: i32,
+    control_count: usize,
+    control_index: usize,
+}
+
+impl PdfFormFieldLayout {
+    /// Returns the [PdfFormFieldType] of the form field.
+    #[inline]
+    pub fn field_type(&self) -> PdfFormFieldType {
+        self.field_type
+    }
+
+    /// Returns the fully qualified name of the form field, if any.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the current value of the form field, if any.
+    #[inline]
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the horizontal pixel position of the left edge of the form field widget.
+    #[inline]
+    pub fn left(&self) -> i32 {
+        self.left
+    }
+
+    /// Returns the vertical pixel position of the top edge of the form field widget.
+    #[inline]
+    pub fn top(&self) -> i32 {
+        self.top
+    }
+
+    /// Returns the width of the form field widget in pixels.
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the height of the form field widget in pixels.
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns the number of widget controls belonging to the form field.
+    ///
+    /// Most form fields have a single widget control, but a group of radio buttons
+    /// (or, less commonly, checkboxes) is represented by a single form field with
+    /// one widget control for each option in the group.
+    #[inline]
+    pub fn control_count(&self) -> usize {
+        self.control_count
+    }
+
+    /// Returns the zero-based index of this widget control within the form field's
+    /// widget controls. The index will always be less than [PdfFormFieldLayout::control_count()].
+    #[inline]
+    pub fn control_index(&self) -> usize {
+        self.control_index
+    }
+}
+
+/// A single selectable option in a combo box or list box form field, as returned by
+/// the [PdfForm::options()] function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfFormFieldOption {
+    index: usize,
+    label: String,
+    is_selected: bool,
+}
+
+impl PdfFormFieldOption {
+    /// Returns the zero-based index of this option in the form field's list of options.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the display label of this option.
+    #[inline]
+    pub fn label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    /// Returns `true` if this option is currently selected.
+    #[inline]
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+}
+
+/// The value of a form field as displayed to the user, as returned by the
+/// [PdfForm::field_display_value()] function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfFormFieldDisplayValue {
+    /// The formatted value shown in the form field's appearance stream, for instance
+    /// a date reformatted by the field's format script.
+    Formatted(String),
+
+    /// The raw value of the form field, returned when no formatted value could be
+    /// determined from the form field's appearance stream.
+    Raw(String),
+}
+
+impl PdfFormFieldDisplayValue {
+    /// Returns the text of this [PdfFormFieldDisplayValue], irrespective of whether
+    /// it is formatted or raw.
+    #[inline]
+    pub fn value(&self) -> &str {
+        match self {
+            PdfFormFieldDisplayValue::Formatted(value) | PdfFormFieldDisplayValue::Raw(value) => {
+                value.as_str()
+            }
+        }
+    }
+
+    /// Returns `true` if this [PdfFormFieldDisplayValue] was taken from the form field's
+    /// appearance stream.
+    #[inline]
+    pub fn is_formatted(&self) -> bool {
+        matches!(self, PdfFormFieldDisplayValue::Formatted(_))
+    }
+}
+
 /// The [PdfForm] embedded inside a `PdfDocument`.
 pub struct PdfForm<'a> {
     form_handle: FPDF_FORMHANDLE,
     document_handle: FPDF_DOCUMENT,
     #[allow(dead_code)]
-    // The form_fill_info field is not currently used, but we expect it to be in future
+    // The form_fill_info field is never read, but Pdfium retains a pointer to it for as long
+    // as the form fill environment exists, so it must remain pinned in memory until then.
     form_fill_info: Pin<Box<FPDF_FORMFILLINFO>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
@@ -215,6 +413,836 @@ impl<'a> PdfForm<'a> {
         PdfFormType::from_pdfium(self.bindings.FPDF_GetFormType(self.document_handle) as u32)
             .unwrap()
     }
+
+    /// Returns `true` if this [PdfForm] defines AcroForm fields that can be rendered and
+    /// filled without XFA support.
+    ///
+    /// This is the case both for plain AcroForm documents and for documents of type
+    /// [PdfFormType::XfaForeground], where an XFA form is layered over an AcroForm that
+    /// remains usable as a fallback.
+    #[inline]
+    pub fn has_acroform_fields(&self) -> bool {
+        matches!(
+            self.form_type(),
+            PdfFormType::Acrobat | PdfFormType::XfaForeground
+        )
+    }
+
+    /// Returns `true` if this [PdfForm] contains an XFA form, either as the only form
+    /// definition ([PdfFormType::XfaFull]) or layered over an AcroForm
+    /// ([PdfFormType::XfaForeground]).
+    ///
+    /// When this function returns `true` but [PdfForm::has_acroform_fields()] returns `false`,
+    /// the form can only be rendered correctly by an XFA-capable build of Pdfium.
+    #[inline]
+    pub fn has_xfa(&self) -> bool {
+        matches!(
+            self.form_type(),
+            PdfFormType::XfaFull | PdfFormType::XfaForeground
+        )
+    }
+
+    /// Returns the [PdfFormFieldType] of the form field associated with the given widget
+    /// annotation, or `None` if the annotation is not associated with a form field.
+    pub fn field_type(&self, annotation: &PdfPageAnnotation) -> Option<PdfFormFieldType> {
+        let field_type = self
+            .bindings
+            .FPDFAnnot_GetFormFieldType(self.form_handle, *annotation.handle());
+
+        if field_type < 0 {
+            None
+        } else {
+            PdfFormFieldType::from_pdfium(field_type as u32).ok()
+        }
+    }
+
+    /// Returns the [PdfFormFieldFlags] set on the form field associated with the given widget
+    /// annotation. No flags are set if the annotation is not associated with a form field.
+    ///
+    /// Flags that Pdfium reports but that are not defined by [PdfFormFieldFlags]
+    /// are ignored.
+    pub fn field_flags(&self, annotation: &PdfPageAnnotation) -> PdfFormFieldFlags {
+        PdfFormFieldFlags::from_bits_truncate(
+            self.bindings
+                .FPDFAnnot_GetFormFieldFlags(self.form_handle, *annotation.handle())
+                as u32,
+        )
+    }
+
+    /// Returns the number of widget controls belonging to the form field associated with the
+    /// given widget annotation, or `None` if the annotation is not associated with a form field.
+    ///
+    /// Most form fields have a single widget control, but a group of radio buttons
+    /// (or, less commonly, checkboxes) is represented by a single form field with
+    /// one widget control for each option in the group.
+    pub fn control_count(&self, annotation: &PdfPageAnnotation) -> Option<usize> {
+        let control_count = self
+            .bindings
+            .FPDFAnnot_GetFormControlCount(self.form_handle, *annotation.handle());
+
+        if control_count < 0 {
+            None
+        } else {
+            Some(control_count as usize)
+        }
+    }
+
+    /// Returns the zero-based index of the given widget annotation within the widget controls
+    /// of its associated form field, or `None` if the annotation is not associated with
+    /// a form field. The index will always be less than [PdfForm::control_count()].
+    pub fn control_index(&self, annotation: &PdfPageAnnotation) -> Option<usize> {
+        let control_index = self
+            .bindings
+            .FPDFAnnot_GetFormControlIndex(self.form_handle, *annotation.handle());
+
+        if control_index < 0 {
+            None
+        } else {
+            Some(control_index as usize)
+        }
+    }
+
+    /// Returns the fully qualified name of the form field associated with the given
+    /// widget annotation, or `None` if the annotation is not associated with a form field.
+    pub fn field_name(&self, annotation: &PdfPageAnnotation) -> Option<String> {
+        let annotation_handle = *annotation.handle();
+
+        self.get_field_string(|buffer, buffer_length| {
+            self.bindings.FPDFAnnot_GetFormFieldName(
+                self.form_handle,
+                annotation_handle,
+                buffer,
+                buffer_length,
+            )
+        })
+    }
+
+    /// Returns the current value of the form field associated with the given widget
+    /// annotation, or `None` if the field has no value.
+    ///
+    /// For text fields, this is the text entered in the field. For combo boxes and list boxes,
+    /// this is the selected option. For checkboxes and radio buttons, this is the export value
+    /// of the selected state, typically "Off" when the control is not selected.
+    pub fn field_value(&self, annotation: &PdfPageAnnotation) -> Option<String> {
+        let annotation_handle = *annotation.handle();
+
+        self.get_field_string(|buffer, buffer_length| {
+            self.bindings.FPDFAnnot_GetFormFieldValue(
+                self.form_handle,
+                annotation_handle,
+                buffer,
+                buffer_length,
+            )
+        })
+    }
+
+    /// Returns the value of the form field associated with the given widget annotation as it is
+    /// displayed to the user, or `None` if the field has no value.
+    ///
+    /// Text fields and combo boxes may have a format script that changes how their value is
+    /// displayed without changing the value itself; for instance, a date field might store the
+    /// value `2024-01-15` but display `01/15/2024`. The formatted value is not stored in the
+    /// form field, so it is instead recovered from the text drawn by the normal appearance stream
+    /// of the widget annotation. This only succeeds if the appearance stream draws its text using
+    /// literal strings in a simple font encoding, as is typical for form fields; if no formatted
+    /// value can be recovered, the raw value returned by [PdfForm::field_value()] is returned
+    /// instead. The returned [PdfFormFieldDisplayValue] indicates which of the two was returned.
+    ///
+    /// The appearance stream reflects the value at the time the appearance stream was last
+    /// generated. Pdfium does not run format scripts, so after changing the value of a field
+    /// using [PdfForm::set_field_value()], the raw value will usually be displayed.
+    pub fn field_display_value(
+        &self,
+        annotation: &PdfPageAnnotation,
+    ) -> Option<PdfFormFieldDisplayValue> {
+        if matches!(
+            self.field_type(annotation),
+            Some(PdfFormFieldType::TextField) | Some(PdfFormFieldType::ComboBox)
+        ) {
+            let annotation_handle = *annotation.handle();
+
+            let formatted = self
+                .get_field_string(|buffer, buffer_length| {
+                    self.bindings.FPDFAnnot_GetAP(
+                        annotation_handle,
+                        FPDF_ANNOT_APPEARANCEMODE_NORMAL as FPDF_ANNOT_APPEARANCEMODE,
+                        buffer,
+                        buffer_length,
+                    )
+                })
+                .and_then(|content| get_text_shown_by_content_stream(content.as_str()))
+                .map(|lines| {
+                    // Comb fields draw each character separately; other fields draw
+                    // each line of text separately.
+
+                    if self
+                        .field_flags(annotation)
+                        .contains(PdfFormFieldFlags::TEXT_COMB)
+                    {
+                        lines.concat()
+                    } else {
+                        lines.join("\n")
+                    }
+                })
+                .filter(|value| !value.is_empty());
+
+            if let Some(formatted) = formatted {
+                return Some(PdfFormFieldDisplayValue::Formatted(formatted));
+            }
+        }
+
+        self.field_value(annotation)
+            .map(PdfFormFieldDisplayValue::Raw)
+    }
+
+    /// Sets the value of the text field associated with the given widget annotation on the
+    /// given [PdfPage], replacing any existing value.
+    ///
+    /// The value is entered using Pdfium's form filling functions, exactly as if a user had
+    /// typed it into the field, so the field's appearance is regenerated and the new value
+    /// is visible when the page is next rendered.
+    ///
+    /// An error is returned if the field is not a text field, if the field is read-only,
+    /// if the value contains line breaks but the field is not a multiline text field, or if
+    /// the value is longer than the maximum length specified by the widget annotation.
+    /// If the maximum length is instead inherited from a parent field, Pdfium silently
+    /// truncates the value to that length.
+    pub fn set_field_value(
+        &self,
+        page: &PdfPage,
+        annotation: &PdfPageAnnotation,
+        value: &str,
+    ) -> Result<(), PdfiumError> {
+        if self.field_type(annotation) != Some(PdfFormFieldType::TextField) {
+            return Err(PdfiumError::FormFieldNotTextField);
+        }
+
+        let annotation_handle = *annotation.handle();
+
+        let flags = self.field_flags(annotation);
+
+        if flags.contains(PdfFormFieldFlags::READ_ONLY) {
+            return Err(PdfiumError::FormFieldReadOnly);
+        }
+
+        if !flags.contains(PdfFormFieldFlags::TEXT_MULTILINE) && value.contains(['\r', '\n']) {
+            return Err(PdfiumError::FormFieldValueContainsLineBreaks);
+        }
+
+        let mut max_length: c_float = 0.0;
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFAnnot_GetNumberValue(
+                annotation_handle,
+                "MaxLen",
+                &mut max_length,
+            ))
+            && max_length > 0.0
+            && value.chars().count() > max_length as usize
+        {
+            return Err(PdfiumError::FormFieldValueExceedsMaxLength);
+        }
+
+        let page_handle = *page.handle();
+
+        // Pdfium only accepts keyboard-style input for pages it has been told are loaded.
+        // Focusing the field, selecting its existing text, and replacing the selection
+        // replaces the field's value; removing focus commits the value and regenerates
+        // the field's appearance stream.
+
+        self.bindings
+            .FORM_OnAfterLoadPage(page_handle, self.form_handle);
+
+        let is_value_set = self.bindings.is_true(
+            self.bindings
+                .FORM_SetFocusedAnnot(self.form_handle, annotation_handle),
+        ) && self.bindings.is_true(
+            self.bindings
+                .FORM_SelectAllText(self.form_handle, page_handle),
+        );
+
+        if is_value_set {
+            self.bindings
+                .FORM_ReplaceSelection_str(self.form_handle, page_handle, value);
+        }
+
+        let is_focus_killed = self
+            .bindings
+            .is_true(self.bindings.FORM_ForceToKillFocus(self.form_handle));
+
+        self.bindings
+            .FORM_OnBeforeClosePage(page_handle, self.form_handle);
+
+        if is_value_set && is_focus_killed {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns `true` if the checkbox or radio button associated with the given widget
+    /// annotation is currently checked. Returns `false` for any other type of form field.
+    #[inline]
+    pub fn is_checked(&self, annotation: &PdfPageAnnotation) -> bool {
+        self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_IsChecked(self.form_handle, *annotation.handle()),
+        )
+    }
+
+    /// Checks or unchecks the checkbox or radio button associated with the given widget
+    /// annotation on the given [PdfPage].
+    ///
+    /// The control is toggled using Pdfium's form filling functions, exactly as if a user
+    /// had activated it, so the widget's appearance is updated and the new state is visible
+    /// when the page is next rendered. Checking a radio button unchecks the other radio buttons
+    /// in the same group. A checked radio button cannot be unchecked directly; instead,
+    /// check another radio button in the same group.
+    ///
+    /// An error is returned if the field is not a checkbox or radio button, or if the field
+    /// is read-only.
+    pub fn set_checked(
+        &self,
+        page: &PdfPage,
+        annotation: &PdfPageAnnotation,
+        checked: bool,
+    ) -> Result<(), PdfiumError> {
+        let field_type = self.field_type(annotation);
+
+        if field_type != Some(PdfFormFieldType::Checkbox)
+            && field_type != Some(PdfFormFieldType::RadioButton)
+        {
+            return Err(PdfiumError::FormFieldNotCheckable);
+        }
+
+        let annotation_handle = *annotation.handle();
+
+        if self
+            .field_flags(annotation)
+            .contains(PdfFormFieldFlags::READ_ONLY)
+        {
+            return Err(PdfiumError::FormFieldReadOnly);
+        }
+
+        if self.is_checked(annotation) == checked {
+            return Ok(());
+        }
+
+        if !checked && field_type == Some(PdfFormFieldType::RadioButton) {
+            return Err(PdfiumError::RadioButtonCannotBeUnchecked);
+        }
+
+        let page_handle = *page.handle();
+
+        // Pressing the space bar while a checkbox or radio button has focus activates it,
+        // toggling a checkbox or selecting a radio button. Removing focus commits the change.
+
+        self.bindings
+            .FORM_OnAfterLoadPage(page_handle, self.form_handle);
+
+        let is_toggled = self.bindings.is_true(
+            self.bindings
+                .FORM_SetFocusedAnnot(self.form_handle, annotation_handle),
+        ) && self.bindings.is_true(self.bindings.FORM_OnChar(
+            self.form_handle,
+            page_handle,
+            ' ' as c_int,
+            0,
+        ));
+
+        self.bindings.FORM_ForceToKillFocus(self.form_handle);
+
+        self.bindings
+            .FORM_OnBeforeClosePage(page_handle, self.form_handle);
+
+        if is_toggled && self.is_checked(annotation) == checked {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns the current values of all named form fields with widget annotations on
+    /// the given [PdfPage], keyed by fully qualified field name.
+    ///
+    /// A form field may have several widgets on the same page, for instance the individual
+    /// buttons in a group of radio buttons; each such field appears only once in the result.
+    pub fn field_values(&self, page: &PdfPage) -> HashMap<String, Option<String>> {
+        page.annotations()
+            .iter()
+            .filter(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Widget)
+            .filter_map(|annotation| {
+                self.field_name(&annotation)
+                    .map(|name| (name, self.field_value(&annotation)))
+            })
+            .collect()
+    }
+
+    /// Returns the number of options available in the combo box or list box form field
+    /// associated with the given widget annotation. Returns 0 if the annotation is not
+    /// a widget for a combo box or list box form field.
+    pub fn option_count(&self, annotation: &PdfPageAnnotation) -> usize {
+        let count = self
+            .bindings
+            .FPDFAnnot_GetOptionCount(self.form_handle, *annotation.handle());
+
+        if count < 0 {
+            0
+        } else {
+            count as usize
+        }
+    }
+
+    /// Returns the display label of the option at the given index in the combo box or
+    /// list box form field associated with the given widget annotation.
+    ///
+    /// Only the requested label is retrieved, so large option lists can be paged through
+    /// or filtered without decoding every label up front.
+    pub fn option_label(
+        &self,
+        annotation: &PdfPageAnnotation,
+        index: usize,
+    ) -> Result<String, PdfiumError> {
+        if index >= self.option_count(annotation) {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        let annotation_handle = *annotation.handle();
+
+        Ok(self
+            .get_field_string(|buffer, buffer_length| {
+                self.bindings.FPDFAnnot_GetOptionLabel(
+                    self.form_handle,
+                    annotation_handle,
+                    index as c_int,
+                    buffer,
+                    buffer_length,
+                )
+            })
+            .unwrap_or_default())
+    }
+
+    /// Returns a lazy iterator over the display labels of all options in the combo box or
+    /// list box form field associated with the given widget annotation. Each label is
+    /// retrieved from Pdfium only when the iterator reaches it.
+    pub fn option_labels<'b>(
+        &'b self,
+        annotation: &'b PdfPageAnnotation<'b>,
+    ) -> impl ExactSizeIterator<Item = String> + 'b {
+        (0..self.option_count(annotation))
+            .map(move |index| self.option_label(annotation, index).unwrap_or_default())
+    }
+
+    /// Returns `true` if the option at the given index in the combo box or list box form field
+    /// associated with the given widget annotation is currently selected.
+    pub fn is_option_selected(
+        &self,
+        annotation: &PdfPageAnnotation,
+        index: usize,
+    ) -> Result<bool, PdfiumError> {
+        if index >= self.option_count(annotation) {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        Ok(self
+            .bindings
+            .is_true(self.bindings.FPDFAnnot_IsOptionSelected(
+                self.form_handle,
+                *annotation.handle(),
+                index as c_int,
+            )))
+    }
+
+    /// Returns the index, display label, and selection state of every option in the combo box
+    /// or list box form field associated with the given widget annotation. Returns an empty
+    /// list if the field has no options, or if the annotation is not a widget for a combo box
+    /// or list box form field.
+    pub fn options(&self, annotation: &PdfPageAnnotation) -> Vec<PdfFormFieldOption> {
+        (0..self.option_count(annotation))
+            .map(|index| PdfFormFieldOption {
+                index,
+                label: self.option_label(annotation, index).unwrap_or_default(),
+                is_selected: self.is_option_selected(annotation, index).unwrap_or(false),
+            })
+            .collect()
+    }
+
+    /// Returns the type, name, current value, and pixel position of every form field widget
+    /// on the page at the given index, with pixel positions calculated as if the page were
+    /// rendered using the given [PdfRenderConfig].
+    ///
+    /// This is intended to help position interactive controls, such as HTML `<input>` elements,
+    /// over a bitmap rendered from the page with the same [PdfRenderConfig]. Only the output
+    /// dimensions and rotation of the [PdfRenderConfig] are taken into account; any custom
+    /// transformation matrix or clipping region is ignored.
+    pub fn field_layout(
+        &self,
+        page_index: PdfPageIndex,
+        config: &PdfRenderConfig,
+    ) -> Result<Vec<PdfFormFieldLayout>, PdfiumError> {
+        if page_index as c_int >= self.bindings.FPDF_GetPageCount(self.document_handle) {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        let page_handle = self
+            .bindings
+            .FPDF_LoadPage(self.document_handle, page_index as c_int);
+
+        if page_handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let settings = config.apply_to_page_size(
+            PdfPoints::new(self.bindings.FPDF_GetPageWidthF(page_handle)),
+            PdfPoints::new(self.bindings.FPDF_GetPageHeightF(page_handle)),
+        );
+
+        let mut result = Vec::new();
+
+        for index in 0..self.bindings.FPDFPage_GetAnnotCount(page_handle) {
+            let annotation_handle = self.bindings.FPDFPage_GetAnnot(page_handle, index);
+
+            if annotation_handle.is_null() {
+                continue;
+            }
+
+            if self.bindings.FPDFAnnot_GetSubtype(annotation_handle)
+                == FPDF_ANNOT_WIDGET as FPDF_ANNOTATION_SUBTYPE
+            {
+                if let Some(layout) =
+                    self.get_field_layout(page_handle, annotation_handle, &settings)
+                {
+                    result.push(layout);
+                }
+            }
+
+            self.bindings.FPDFPage_CloseAnnot(annotation_handle);
+        }
+
+        self.bindings.FPDF_ClosePage(page_handle);
+
+        Ok(result)
+    }
+
+    /// Returns the [PdfFormFieldLayout] for the given widget annotation, or `None` if the
+    /// annotation is not associated with a form field.
+    fn get_field_layout(
+        &self,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        settings: &PdfRenderSettings,
+    ) -> Option<PdfFormFieldLayout> {
+        let field_type = self
+            .bindings
+            .FPDFAnnot_GetFormFieldType(self.form_handle, annotation_handle);
+
+        if field_type < 0 {
+            return None;
+        }
+
+        let field_type = PdfFormFieldType::from_pdfium(field_type as u32).ok()?;
+
+        let control_count = self
+            .bindings
+            .FPDFAnnot_GetFormControlCount(self.form_handle, annotation_handle);
+
+        let control_index = self
+            .bindings
+            .FPDFAnnot_GetFormControlIndex(self.form_handle, annotation_handle);
+
+        if control_count < 0 || control_index < 0 {
+            return None;
+        }
+
+        let mut rect = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: 0.0,
+            bottom: 0.0,
+        };
+
+        if !self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_GetRect(annotation_handle, &mut rect),
+        ) {
+            return None;
+        }
+
+        let (x1, y1) = self.page_to_device(page_handle, rect.left, rect.top, settings)?;
+
+        let (x2, y2) = self.page_to_device(page_handle, rect.right, rect.bottom, settings)?;
+
+        Some(PdfFormFieldLayout {
+            field_type,
+            name: self.get_field_string(|buffer, buffer_length| {
+                self.bindings.FPDFAnnot_GetFormFieldName(
+                    self.form_handle,
+                    annotation_handle,
+                    buffer,
+                    buffer_length,
+                )
+            }),
+            value: self.get_field_string(|buffer, buffer_length| {
+                self.bindings.FPDFAnnot_GetFormFieldValue(
+                    self.form_handle,
+                    annotation_handle,
+                    buffer,
+                    buffer_length,
+                )
+            }),
+            // Page rotation may swap or invert the corners of the rectangle.
+            left: x1.min(x2),
+            top: y1.min(y2),
+            width: (x2 - x1).abs(),
+            height: (y2 - y1).abs(),
+            control_count: control_count as usize,
+            control_index: control_index as usize,
+        })
+    }
+
+    /// Converts the given page coordinates into pixel coordinates using the output
+    /// dimensions and rotation of the given [PdfRenderSettings].
+    fn page_to_device(
+        &self,
+        page_handle: FPDF_PAGE,
+        x: f32,
+        y: f32,
+        settings: &PdfRenderSettings,
+    ) -> Option<(i32, i32)> {
+        let mut device_x: c_int = 0;
+
+        let mut device_y: c_int = 0;
+
+        if self.bindings.is_true(self.bindings.FPDF_PageToDevice(
+            page_handle,
+            0,
+            0,
+            settings.width,
+            settings.height,
+            settings.rotate,
+            x as c_double,
+            y as c_double,
+            &mut device_x,
+            &mut device_y,
+        )) {
+            Some((device_x, device_y))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves a UTF-16LE string from Pdfium using the given two-step retrieval function.
+    fn get_field_string(&self, f: impl Fn(*mut FPDF_WCHAR, c_ulong) -> c_ulong) -> Option<String> {
+        // Retrieving the string from Pdfium is a two-step operation. First, we call
+        // the retrieval function with a null buffer; this will retrieve the length of
+        // the string in bytes. If the length is zero, then there is no such string.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the retrieval function again with a pointer to the buffer;
+        // this will write the string to the buffer in UTF16-LE format.
+
+        let buffer_length = f(null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = f(buffer.as_mut_ptr() as *mut FPDF_WCHAR, buffer_length);
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+/// Returns the character represented by the given byte in PDFDocEncoding. PDFDocEncoding
+/// matches ISO Latin-1 except for the bytes 0x18 - 0x1F and 0x80 - 0xA0, as listed in
+/// Annex D of the PDF Reference Manual version 1.7.
+fn get_char_from_pdf_doc_encoding(code: u8) -> char {
+    const CHARS_18_TO_1F: [char; 8] = [
+        '\u{02D8}', '\u{02C7}', '\u{02C6}', '\u{02D9}', '\u{02DD}', '\u{02DB}', '\u{02DA}',
+        '\u{02DC}',
+    ];
+
+    // 0x9F is undefined in PDFDocEncoding; we map it to the replacement character.
+
+    const CHARS_80_TO_A0: [char; 33] = [
+        '\u{2022}', '\u{2020}', '\u{2021}', '\u{2026}', '\u{2014}', '\u{2013}', '\u{0192}',
+        '\u{2044}', '\u{2039}', '\u{203A}', '\u{2212}', '\u{2030}', '\u{201E}', '\u{201C}',
+        '\u{201D}', '\u{2018}', '\u{2019}', '\u{201A}', '\u{2122}', '\u{FB01}', '\u{FB02}',
+        '\u{0141}', '\u{0152}', '\u{0160}', '\u{0178}', '\u{017D}', '\u{0131}', '\u{0142}',
+        '\u{0153}', '\u{0161}', '\u{017E}', '\u{FFFD}', '\u{20AC}',
+    ];
+
+    match code {
+        0x18..=0x1F => CHARS_18_TO_1F[(code - 0x18) as usize],
+        0x80..=0xA0 => CHARS_80_TO_A0[(code - 0x80) as usize],
+        _ => char::from(code),
+    }
+}
+
+/// Returns the strings drawn by the text showing operators (`Tj`, `TJ`, `'`, and `"`) in the
+/// given content stream, one string per operator, or `None` if any of the strings are
+/// hexadecimal strings, which cannot be decoded without knowledge of the font.
+fn get_text_shown_by_content_stream(content: &str) -> Option<Vec<String>> {
+    // Pdfium decodes the raw bytes of the content stream from PDFDocEncoding before returning
+    // it, so only octal escapes remain to be decoded here. PDFDocEncoding matches the standard
+    // encoding of simple fonts closely enough for form field values.
+
+    let mut result = Vec::new();
+
+    let mut chars = content.chars().peekable();
+
+    // The most recent string operand, if any. An inner value of None indicates
+    // a hexadecimal string.
+
+    let mut operand: Option<Option<String>> = None;
+
+    // The concatenated strings in the array currently being read, if any.
+
+    let mut array: Option<Option<String>> = None;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => {
+                // Comments run to the end of the line.
+
+                for c in chars.by_ref() {
+                    if c == '\r' || c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '(' => {
+                let mut string = String::new();
+
+                let mut depth = 0;
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => match chars.next() {
+                            Some('n') => string.push('\n'),
+                            Some('r') => string.push('\r'),
+                            Some('t') => string.push('\t'),
+                            Some('b') => string.push('\u{8}'),
+                            Some('f') => string.push('\u{c}'),
+                            Some(c @ '0'..='7') => {
+                                let mut code = c.to_digit(8).unwrap_or(0);
+
+                                for _ in 0..2 {
+                                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                                        Some(digit) => {
+                                            code = code * 8 + digit;
+
+                                            chars.next();
+                                        }
+                                        None => break,
+                                    }
+                                }
+
+                                string.push(get_char_from_pdf_doc_encoding((code & 0xff) as u8));
+                            }
+                            Some('\r') => {
+                                // An escaped line break continues the string on the next line.
+
+                                if chars.peek() == Some(&'\n') {
+                                    chars.next();
+                                }
+                            }
+                            Some('\n') => {}
+                            Some(c) => string.push(c),
+                            None => break,
+                        },
+                        '(' => {
+                            depth += 1;
+
+                            string.push(c);
+                        }
+                        ')' if depth == 0 => break,
+                        ')' => {
+                            depth -= 1;
+
+                            string.push(c);
+                        }
+                        c => string.push(c),
+                    }
+                }
+
+                match array.as_mut() {
+                    Some(Some(text)) => text.push_str(string.as_str()),
+                    Some(None) => {}
+                    None => operand = Some(Some(string)),
+                }
+            }
+            '<' if chars.peek() == Some(&'<') => {
+                chars.next();
+            }
+            '<' => {
+                for c in chars.by_ref() {
+                    if c == '>' {
+                        break;
+                    }
+                }
+
+                match array.as_mut() {
+                    Some(text) => *text = None,
+                    None => operand = Some(None),
+                }
+            }
+            '[' => array = Some(Some(String::new())),
+            ']' => operand = array.take(),
+            c if c.is_whitespace() || matches!(c, '>' | '{' | '}') => {}
+            c => {
+                let mut word = String::from(c);
+
+                while let Some(c) = chars.peek() {
+                    if c.is_whitespace() || "()<>[]{}/%".contains(*c) {
+                        break;
+                    }
+
+                    word.push(*c);
+
+                    chars.next();
+                }
+
+                if array.is_some() {
+                    // Numeric kerning adjustments inside a TJ array are ignored.
+
+                    continue;
+                }
+
+                match word.as_str() {
+                    "Tj" | "TJ" | "'" | "\"" => match operand.take() {
+                        Some(Some(text)) => result.push(text),
+                        Some(None) => return None,
+                        None => {}
+                    },
+                    _ => {
+                        // Any other operator or operand discards a pending string operand.
+
+                        operand = None;
+                    }
+                }
+            }
+        }
+    }
+
+    Some(result)
 }
 
 impl<'a> Drop for PdfForm<'a> {
@@ -225,3 +1253,202 @@ impl<'a> Drop for PdfForm<'a> {
             .FPDFDOC_ExitFormFillEnvironment(self.form_handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::get_text_shown_by_content_stream;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_get_text_shown_by_content_stream() {
+        assert_eq!(
+            get_text_shown_by_content_stream(
+                "/Tx BMC q BT /Helv 12 Tf 2 4 Td (01/15/2024) Tj ET Q EMC"
+            ),
+            Some(vec!["01/15/2024".to_owned()])
+        );
+
+        assert_eq!(
+            get_text_shown_by_content_stream(
+                "BT [(A) -120 (\\(b\\)) 50 (c\\101)] TJ 0 -14 Td (Second line) ' ET"
+            ),
+            Some(vec!["A(b)cA".to_owned(), "Second line".to_owned()])
+        );
+
+        assert_eq!(
+            get_text_shown_by_content_stream("BT (\\200 \\240 \\351) Tj ET"),
+            Some(vec!["\u{2022} \u{20AC} \u{E9}".to_owned()])
+        );
+
+        assert_eq!(get_text_shown_by_content_stream("BT <0041> Tj ET"), None);
+    }
+
+    #[test]
+    fn test_set_checked() -> Result<(), PdfiumError> {
+        // Toggles the first checkbox in the given test PDF file, confirming that both
+        // the checked state and the rendered appearance of the page change.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let render_config = PdfRenderConfig::new().set_target_width(1000);
+
+        for page in document.pages().iter() {
+            for annotation in page.annotations().iter() {
+                if form.field_type(&annotation) != Some(PdfFormFieldType::Checkbox) {
+                    continue;
+                }
+
+                let before = page.render_with_config(&render_config)?.as_bytes().to_vec();
+
+                let was_checked = form.is_checked(&annotation);
+
+                form.set_checked(&page, &annotation, !was_checked)?;
+
+                assert_eq!(form.is_checked(&annotation), !was_checked);
+
+                let after = page.render_with_config(&render_config)?.as_bytes().to_vec();
+
+                assert_ne!(before, after);
+
+                return Ok(());
+            }
+        }
+
+        panic!("No checkbox found in test/form-test.pdf");
+    }
+
+    #[test]
+    fn test_control_count_and_index() -> Result<(), PdfiumError> {
+        // Confirms that every widget annotation associated with a form field in the given
+        // test PDF file reports a control index within the control count of its field.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let mut field_count = 0;
+
+        for page in document.pages().iter() {
+            for annotation in page.annotations().iter() {
+                if form.field_type(&annotation).is_none() {
+                    continue;
+                }
+
+                let control_count = form.control_count(&annotation).unwrap();
+
+                let control_index = form.control_index(&annotation).unwrap();
+
+                assert!(control_index < control_count);
+
+                field_count += 1;
+            }
+        }
+
+        assert!(field_count > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_retains_field_text() -> Result<(), PdfiumError> {
+        // Sets the value of the first editable text field in the given test PDF file,
+        // flattens the page, and confirms that the field's text has become part of
+        // the page content once the document is saved and reloaded.
+
+        const MARKER: &str = "XYZZY";
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        for (index, mut page) in document.pages().iter().enumerate() {
+            let is_value_set = page.annotations().iter().any(|annotation| {
+                form.field_type(&annotation) == Some(PdfFormFieldType::TextField)
+                    && form.set_field_value(&page, &annotation, MARKER).is_ok()
+            });
+
+            if !is_value_set {
+                continue;
+            }
+
+            assert!(!page.text()?.all().contains(MARKER));
+
+            page.flatten()?;
+
+            drop(page);
+
+            let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+            let page = reloaded.pages().get(index as PdfPageIndex)?;
+
+            assert!(page.text()?.all().contains(MARKER));
+
+            return Ok(());
+        }
+
+        panic!("No editable text field found in test/form-test.pdf");
+    }
+
+    #[test]
+    fn test_flatten_retains_checked_checkbox() -> Result<(), PdfiumError> {
+        // Flattens the page containing the first checkbox in the given test PDF file twice,
+        // once with the checkbox checked and once with it unchecked. Once each document is
+        // saved and reloaded, the two pages must render differently with annotations and
+        // form data disabled, confirming the check mark has become part of the page content.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(1000)
+            .render_annotations(false)
+            .render_form_data(false);
+
+        let flatten_with_checkbox = |checked: bool| -> Result<Vec<u8>, PdfiumError> {
+            let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+            let form = document.form().unwrap();
+
+            for (index, mut page) in document.pages().iter().enumerate() {
+                let is_checkbox_set = page.annotations().iter().any(|annotation| {
+                    form.field_type(&annotation) == Some(PdfFormFieldType::Checkbox)
+                        && form.set_checked(&page, &annotation, checked).is_ok()
+                });
+
+                if !is_checkbox_set {
+                    continue;
+                }
+
+                page.flatten()?;
+
+                drop(page);
+
+                let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+                let page = reloaded.pages().get(index as PdfPageIndex)?;
+
+                assert!(page.annotations().iter().all(|annotation| {
+                    annotation.annotation_type() != PdfPageAnnotationType::Widget
+                }));
+
+                let bytes = page.render_with_config(&render_config)?.as_bytes().to_vec();
+
+                return Ok(bytes);
+            }
+
+            panic!("No checkbox found in test/form-test.pdf");
+        };
+
+        assert_ne!(flatten_with_checkbox(true)?, flatten_with_checkbox(false)?);
+
+        Ok(())
+    }
+}
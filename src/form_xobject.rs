@@ -0,0 +1,148 @@
+//! Defines the [PdfFormXObject] struct, exposing functionality related to a reusable
+//! form XObject created from the content of a `PdfPage`.
+
+use crate::bindgen::FPDF_XOBJECT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page_object::PdfPageObject;
+use crate::page_object_form_fragment::PdfPageFormFragmentObject;
+
+/// A reusable form XObject, created from the content of a single `PdfPage`.
+///
+/// The content of the source page is copied into the destination document once, as a single
+/// shared XObject resource. Any number of [PdfPageObject] placements can then be created from
+/// the template using the [PdfFormXObject::create_object()] function, each with its own
+/// transformation matrix; every placement references the same shared resource rather than
+/// duplicating the source page content. This makes form XObjects well suited to stamps,
+/// letterheads, and watermarks that repeat across many pages.
+///
+/// Use the `PdfPageObjects::create_form_xobject_from_page()` function to create a new
+/// [PdfFormXObject]. Dropping the template does not affect any placements already added to a page.
+pub struct PdfFormXObject<'a> {
+    handle: FPDF_XOBJECT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfFormXObject<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_XOBJECT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfFormXObject { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfFormXObject].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Creates a new placement of this [PdfFormXObject] as a form fragment page object.
+    ///
+    /// The returned object is not attached to any page; add it to a page objects collection
+    /// using the `PdfPageObjectsCommon::add_object()` function, then position it using the
+    /// transformation functions on `PdfPageObjectCommon`. The object must be added to a page
+    /// in the same document used to create this [PdfFormXObject].
+    pub fn create_object(&self) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let handle = self.bindings.FPDF_NewFormObjectFromXObject(self.handle);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageObject::FormFragment(
+                PdfPageFormFragmentObject::from_pdfium(handle, None, None, self.bindings),
+            ))
+        }
+    }
+}
+
+impl<'a> Drop for PdfFormXObject<'a> {
+    /// Closes this [PdfFormXObject], releasing held memory. Placements created from
+    /// this [PdfFormXObject] remain valid.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_CloseXObject(self.handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_create_object_placement() -> Result<(), PdfiumError> {
+        // Creates a form XObject from a page containing a single rectangle, places it twice
+        // on a page in another document, and confirms each placement is positioned
+        // independently by its own transformation matrix.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let source = pdfium.create_new_pdf()?;
+
+        source
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?
+            .objects_mut()
+            .create_path_object_rect(
+                PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+                None,
+                None,
+                Some(PdfColor::SOLID_RED),
+            )?;
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let xobject = page.objects().create_form_xobject_from_page(&source, 0)?;
+
+        let mut first = xobject.create_object()?;
+
+        first.translate(PdfPoints::new(50.0), PdfPoints::new(300.0))?;
+
+        let first = page.objects_mut().add_object(first)?;
+
+        let mut second = xobject.create_object()?;
+
+        second.scale(2.0, 2.0)?;
+
+        let second = page.objects_mut().add_object(second)?;
+
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(first.object_type(), PdfPageObjectType::FormFragment);
+
+        let bounds = first.bounds()?;
+
+        assert_eq!(
+            (
+                bounds.left.value,
+                bounds.bottom.value,
+                bounds.right.value,
+                bounds.top.value
+            ),
+            (150.0, 400.0, 250.0, 500.0)
+        );
+
+        let bounds = second.bounds()?;
+
+        assert_eq!(
+            (
+                bounds.left.value,
+                bounds.bottom.value,
+                bounds.right.value,
+                bounds.top.value
+            ),
+            (200.0, 200.0, 400.0, 400.0)
+        );
+
+        Ok(())
+    }
+}
@@ -31,11 +31,14 @@ pub mod color;
 pub mod color_space;
 pub mod destination;
 pub mod document;
+pub mod document_annotations;
+pub mod document_diff;
 pub mod error;
 pub mod font;
 pub mod font_glyph;
 pub mod font_glyphs;
 pub mod form;
+pub mod form_xobject;
 pub mod link;
 pub mod metadata;
 pub mod page;
@@ -46,6 +49,7 @@ pub mod page_annotation_highlight;
 pub mod page_annotation_ink;
 pub mod page_annotation_link;
 pub mod page_annotation_objects;
+pub mod page_annotation_overlay;
 pub mod page_annotation_popup;
 mod page_annotation_private; // Keep private so that the PdfPageAnnotationPrivate trait is not exposed.
 pub mod page_annotation_square;
@@ -57,6 +61,7 @@ pub mod page_annotation_underline;
 pub mod page_annotation_unsupported;
 pub mod page_annotations;
 pub mod page_boundaries;
+mod page_cache; // Keep private since not part of the public API.
 mod page_index_cache; // Keep private since not part of the public API.
 pub mod page_links;
 pub mod page_object;
@@ -72,9 +77,13 @@ pub mod page_objects;
 pub mod page_objects_common;
 mod page_objects_private; // Keep private so that the PdfPageObjectsPrivate trait is not exposed.
 pub mod page_size;
+pub mod page_struct_element;
 pub mod page_text;
 pub mod page_text_char;
 pub mod page_text_chars;
+pub mod page_text_hit_map;
+pub mod page_text_line;
+pub mod page_text_search;
 pub mod page_text_segment;
 pub mod page_text_segments;
 pub mod pages;
@@ -99,17 +108,19 @@ pub mod prelude {
     pub use super::{
         action::*, attachment::*, attachments::*, bindings::*, bitmap::*, bookmark::*,
         bookmarks::*, clip_path::*, color::*, color_space::*, destination::*, document::*,
-        error::*, font::*, font_glyph::*, font_glyphs::*, form::*, link::*, metadata::*, page::*,
+        document_annotations::*, document_diff::*, error::*, font::*, font_glyph::*,
+        font_glyphs::*, form::*, form_xobject::*, link::*, metadata::*, page::*,
         page_annotation::*, page_annotation_circle::*, page_annotation_free_text::*,
         page_annotation_highlight::*, page_annotation_ink::*, page_annotation_link::*,
-        page_annotation_objects::*, page_annotation_popup::*, page_annotation_square::*,
-        page_annotation_squiggly::*, page_annotation_stamp::*, page_annotation_strikeout::*,
-        page_annotation_text::*, page_annotation_underline::*, page_annotation_unsupported::*,
-        page_annotations::*, page_boundaries::*, page_links::*, page_object::*,
-        page_object_form_fragment::*, page_object_group::*, page_object_image::*,
+        page_annotation_objects::*, page_annotation_overlay::*, page_annotation_popup::*,
+        page_annotation_square::*, page_annotation_squiggly::*, page_annotation_stamp::*,
+        page_annotation_strikeout::*, page_annotation_text::*, page_annotation_underline::*,
+        page_annotation_unsupported::*, page_annotations::*, page_boundaries::*, page_links::*,
+        page_object::*, page_object_form_fragment::*, page_object_group::*, page_object_image::*,
         page_object_path::*, page_object_shading::*, page_object_text::*,
         page_object_unsupported::*, page_objects::*, page_objects_common::*, page_size::*,
-        page_text::*, page_text_char::*, page_text_chars::*, page_text_segment::*,
+        page_struct_element::*, page_text::*, page_text_char::*, page_text_chars::*,
+        page_text_hit_map::*, page_text_line::*, page_text_search::*, page_text_segment::*,
         page_text_segments::*, pages::*, path_segment::*, path_segments::*, pdfium::*,
         permissions::*, render_config::*, signature::*, signatures::*, transform::*,
     };
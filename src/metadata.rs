@@ -128,8 +128,11 @@ impl<'a> PdfMetadata<'a> {
         result.map(|value| PdfDocumentMetadataTag::new(tag, value))
     }
 
+    /// Returns the value associated with the given key in the document information dictionary,
+    /// if any. Any key may be requested, including custom keys not covered by
+    /// [PdfDocumentMetadataTagType].
     #[inline]
-    fn get_raw_metadata_tag(&self, tag: &str) -> Option<String> {
+    pub(crate) fn get_raw_metadata_tag(&self, tag: &str) -> Option<String> {
         // Retrieving the tag text from Pdfium is a two-step operation. First, we call
         // FPDF_GetMetaText() with a null buffer; this will retrieve the length of
         // the metadata text in bytes. If the length is zero, then there is no such tag.
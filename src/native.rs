@@ -1,13 +1,13 @@
 use crate::bindgen::{
     size_t, FPDFANNOT_COLORTYPE, FPDF_ACTION, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE,
     FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
-    FPDF_BYTESTRING, FPDF_CLIPPATH, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD,
-    FPDF_FILEACCESS, FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO,
+    FPDF_BYTESTRING, FPDF_CLIPPATH, FPDF_COLORSCHEME, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE,
+    FPDF_DWORD, FPDF_FILEACCESS, FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO,
     FPDF_FORMHANDLE, FPDF_GLYPHPATH, FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE,
     FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE,
     FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRING, FPDF_STRUCTELEMENT,
-    FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FS_FLOAT,
-    FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF,
+    FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING,
+    FPDF_XOBJECT, FS_FLOAT, FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF, FS_SIZEF, IFSDK_PAUSE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use libloading::{Library, Symbol};
@@ -41,11 +41,15 @@ impl DynamicPdfiumBindings {
         result.extern_FPDF_GetDocPermissions()?;
         result.extern_FPDF_GetSecurityHandlerRevision()?;
         result.extern_FPDF_GetPageCount()?;
+        result.extern_FPDF_GetPageSizeByIndexF()?;
         result.extern_FPDF_LoadPage()?;
         result.extern_FPDF_ClosePage()?;
         result.extern_FPDF_ImportPagesByIndex()?;
         result.extern_FPDF_ImportPages()?;
         result.extern_FPDF_ImportNPagesToOne()?;
+        result.extern_FPDF_NewXObjectFromPage()?;
+        result.extern_FPDF_CloseXObject()?;
+        result.extern_FPDF_NewFormObjectFromXObject()?;
         result.extern_FPDF_GetPageLabel()?;
         result.extern_FPDF_GetPageBoundingBox()?;
         result.extern_FPDF_GetPageWidthF()?;
@@ -102,6 +106,10 @@ impl DynamicPdfiumBindings {
         result.extern_FPDFBitmap_GetStride()?;
         result.extern_FPDF_RenderPageBitmap()?;
         result.extern_FPDF_RenderPageBitmapWithMatrix()?;
+        result.extern_FPDF_RenderPageBitmap_Start()?;
+        result.extern_FPDF_RenderPageBitmapWithColorScheme_Start()?;
+        result.extern_FPDF_RenderPage_Close()?;
+        result.extern_FPDF_PageToDevice()?;
         result.extern_FPDFAnnot_IsSupportedSubtype()?;
         result.extern_FPDFPage_CreateAnnot()?;
         result.extern_FPDFPage_GetAnnotCount()?;
@@ -166,6 +174,13 @@ impl DynamicPdfiumBindings {
         result.extern_FPDFPage_Flatten()?;
         result.extern_FPDF_SetFormFieldHighlightColor()?;
         result.extern_FPDF_SetFormFieldHighlightAlpha()?;
+        result.extern_FORM_OnAfterLoadPage()?;
+        result.extern_FORM_OnBeforeClosePage()?;
+        result.extern_FORM_SetFocusedAnnot()?;
+        result.extern_FORM_SelectAllText()?;
+        result.extern_FORM_ReplaceSelection()?;
+        result.extern_FORM_ForceToKillFocus()?;
+        result.extern_FORM_OnChar()?;
         result.extern_FPDF_FFLDraw()?;
         result.extern_FPDFBookmark_GetFirstChild()?;
         result.extern_FPDFBookmark_GetNextSibling()?;
@@ -533,6 +548,24 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_GetPageCount\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_GetPageSizeByIndexF(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                document: FPDF_DOCUMENT,
+                page_index: c_int,
+                size: *mut FS_SIZEF,
+            ) -> FPDF_BOOL,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_GetPageSizeByIndexF\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDF_LoadPage(
@@ -608,6 +641,45 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_ImportNPagesToOne\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_NewXObjectFromPage(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                dest_doc: FPDF_DOCUMENT,
+                src_doc: FPDF_DOCUMENT,
+                src_page_index: c_int,
+            ) -> FPDF_XOBJECT,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_NewXObjectFromPage\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_CloseXObject(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(xobject: FPDF_XOBJECT)>, libloading::Error> {
+        unsafe { self.library.get(b"FPDF_CloseXObject\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_NewFormObjectFromXObject(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(xobject: FPDF_XOBJECT) -> FPDF_PAGEOBJECT>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_NewFormObjectFromXObject\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDF_GetPageLabel(
@@ -1399,6 +1471,92 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_RenderPageBitmapWithMatrix\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_RenderPageBitmap_Start(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                bitmap: FPDF_BITMAP,
+                page: FPDF_PAGE,
+                start_x: c_int,
+                start_y: c_int,
+                size_x: c_int,
+                size_y: c_int,
+                rotate: c_int,
+                flags: c_int,
+                pause: *mut IFSDK_PAUSE,
+            ) -> c_int,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_RenderPageBitmap_Start\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_RenderPageBitmapWithColorScheme_Start(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                bitmap: FPDF_BITMAP,
+                page: FPDF_PAGE,
+                start_x: c_int,
+                start_y: c_int,
+                size_x: c_int,
+                size_y: c_int,
+                rotate: c_int,
+                flags: c_int,
+                color_scheme: *const FPDF_COLORSCHEME,
+                pause: *mut IFSDK_PAUSE,
+            ) -> c_int,
+        >,
+        libloading::Error,
+    > {
+        unsafe {
+            self.library
+                .get(b"FPDF_RenderPageBitmapWithColorScheme_Start\0")
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_RenderPage_Close(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(page: FPDF_PAGE)>, libloading::Error> {
+        unsafe { self.library.get(b"FPDF_RenderPage_Close\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_PageToDevice(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                page: FPDF_PAGE,
+                start_x: c_int,
+                start_y: c_int,
+                size_x: c_int,
+                size_y: c_int,
+                rotate: c_int,
+                page_x: c_double,
+                page_y: c_double,
+                device_x: *mut c_int,
+                device_y: *mut c_int,
+            ) -> FPDF_BOOL,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_PageToDevice\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDFAnnot_IsSupportedSubtype(
@@ -2268,6 +2426,103 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_SetFormFieldHighlightAlpha\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_OnAfterLoadPage(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(page: FPDF_PAGE, handle: FPDF_FORMHANDLE)>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_OnAfterLoadPage\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_OnBeforeClosePage(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(page: FPDF_PAGE, handle: FPDF_FORMHANDLE)>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_OnBeforeClosePage\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_SetFocusedAnnot(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(handle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_SetFocusedAnnot\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_SelectAllText(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_SelectAllText\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_ReplaceSelection(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                hHandle: FPDF_FORMHANDLE,
+                page: FPDF_PAGE,
+                wsText: FPDF_WIDESTRING,
+            ),
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_ReplaceSelection\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_ForceToKillFocus(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(hHandle: FPDF_FORMHANDLE) -> FPDF_BOOL>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_ForceToKillFocus\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FORM_OnChar(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                hHandle: FPDF_FORMHANDLE,
+                page: FPDF_PAGE,
+                nChar: c_int,
+                modifier: c_int,
+            ) -> FPDF_BOOL,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FORM_OnChar\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     #[allow(clippy::type_complexity)]
@@ -4783,6 +5038,17 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         unsafe { self.extern_FPDF_GetPageCount().unwrap()(document) }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_GetPageSizeByIndexF(
+        &self,
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        size: *mut FS_SIZEF,
+    ) -> FPDF_BOOL {
+        unsafe { self.extern_FPDF_GetPageSizeByIndexF().unwrap()(document, page_index, size) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_LoadPage(&self, document: FPDF_DOCUMENT, page_index: c_int) -> FPDF_PAGE {
@@ -4855,6 +5121,29 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_NewXObjectFromPage(
+        &self,
+        dest_doc: FPDF_DOCUMENT,
+        src_doc: FPDF_DOCUMENT,
+        src_page_index: c_int,
+    ) -> FPDF_XOBJECT {
+        unsafe { self.extern_FPDF_NewXObjectFromPage().unwrap()(dest_doc, src_doc, src_page_index) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_CloseXObject(&self, xobject: FPDF_XOBJECT) {
+        unsafe { self.extern_FPDF_CloseXObject().unwrap()(xobject) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_NewFormObjectFromXObject(&self, xobject: FPDF_XOBJECT) -> FPDF_PAGEOBJECT {
+        unsafe { self.extern_FPDF_NewFormObjectFromXObject().unwrap()(xobject) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageWidthF(&self, page: FPDF_PAGE) -> c_float {
@@ -5412,6 +5701,87 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmap_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        unsafe {
+            self.extern_FPDF_RenderPageBitmap_Start().unwrap()(
+                bitmap, page, start_x, start_y, size_x, size_y, rotate, flags, pause,
+            )
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmapWithColorScheme_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        color_scheme: *const FPDF_COLORSCHEME,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        unsafe {
+            self.extern_FPDF_RenderPageBitmapWithColorScheme_Start()
+                .unwrap()(
+                bitmap,
+                page,
+                start_x,
+                start_y,
+                size_x,
+                size_y,
+                rotate,
+                flags,
+                color_scheme,
+                pause,
+            )
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        unsafe { self.extern_FPDF_RenderPage_Close().unwrap()(page) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_PageToDevice(
+        &self,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        page_x: c_double,
+        page_y: c_double,
+        device_x: *mut c_int,
+        device_y: *mut c_int,
+    ) -> FPDF_BOOL {
+        unsafe {
+            self.extern_FPDF_PageToDevice().unwrap()(
+                page, start_x, start_y, size_x, size_y, rotate, page_x, page_y, device_x, device_y,
+            )
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
@@ -6019,6 +6389,59 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_OnAfterLoadPage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        unsafe { self.extern_FORM_OnAfterLoadPage().unwrap()(page, handle) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_OnBeforeClosePage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        unsafe { self.extern_FORM_OnBeforeClosePage().unwrap()(page, handle) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_SetFocusedAnnot(&self, handle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        unsafe { self.extern_FORM_SetFocusedAnnot().unwrap()(handle, annot) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_SelectAllText(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        unsafe { self.extern_FORM_SelectAllText().unwrap()(hHandle, page) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_ReplaceSelection(
+        &self,
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        wsText: FPDF_WIDESTRING,
+    ) {
+        unsafe { self.extern_FORM_ReplaceSelection().unwrap()(hHandle, page, wsText) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_ForceToKillFocus(&self, hHandle: FPDF_FORMHANDLE) -> FPDF_BOOL {
+        unsafe { self.extern_FORM_ForceToKillFocus().unwrap()(hHandle) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FORM_OnChar(
+        &self,
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        nChar: c_int,
+        modifier: c_int,
+    ) -> FPDF_BOOL {
+        unsafe { self.extern_FORM_OnChar().unwrap()(hHandle, page, nChar, modifier) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_FFLDraw(
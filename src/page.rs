@@ -2,26 +2,61 @@
 //! `PdfPages` collection.
 
 use crate::bindgen::{
-    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT, FPDF_BOOL, FPDF_PAGE, FS_RECTF,
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, FLATTEN_FAIL, FLATTEN_NOTHINGTODO,
+    FLATTEN_SUCCESS, FLAT_PRINT, FPDF_ANNOT, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE,
+    FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_HIGHLIGHT, FPDF_BOOL, FPDF_PAGE, FPDF_PAGEOBJECT,
+    FPDF_RENDER_DONE, FPDF_RENDER_FAILED, FPDF_RENDER_TOBECONTINUED, FS_MATRIX, FS_QUADPOINTSF,
+    FS_RECTF,
 };
 use crate::bindings::PdfiumLibraryBindings;
-use crate::bitmap::{PdfBitmap, PdfBitmapFormat, PdfBitmapRotation};
-use crate::document::PdfDocument;
+use crate::bitmap::{PdfBitmap, PdfBitmapFormat, PdfBitmapRotation, Pixels};
+use crate::color::PdfColor;
+use crate::document::{PdfDocument, PdfDocumentVersion};
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::font::PdfFont;
+use crate::page_annotation::PdfPageAnnotationType;
+use crate::page_annotation_overlay::PdfPageAnnotationOverlay;
+use crate::page_annotations::PdfPageAnnotationIndex;
 use crate::page_boundaries::PdfPageBoundaries;
+use crate::page_cache::PdfCachedPage;
 use crate::page_index_cache::PdfPageIndexCache;
 use crate::page_links::PdfPageLinks;
+use crate::page_object::{PdfPageObject, PdfPageObjectCommon, PdfPageObjectType};
+use crate::page_object_private::internal::PdfPageObjectPrivate;
+use crate::page_object_text::PdfPageTextRenderMode;
 use crate::page_objects::PdfPageObjects;
 use crate::page_objects_common::PdfPageObjectsCommon;
 use crate::page_size::PdfPagePaperSize;
+use crate::page_struct_element::PdfStructElement;
 use crate::page_text::PdfPageText;
+use crate::page_text_hit_map::PdfPageTextHitMap;
+use crate::page_text_search::PdfSearchOptions;
+use crate::pages::PdfPages;
 use crate::prelude::PdfPageAnnotations;
 use crate::render_config::{PdfRenderConfig, PdfRenderSettings};
+use crate::utils::pause::get_pdfium_pause_from_callback;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
-use std::os::raw::c_int;
+use std::os::raw::{c_double, c_int, c_ulong};
+use std::rc::Rc;
+
+#[cfg(feature = "image")]
+use std::io::Write;
+
+/// The custom annotation dictionary key used to tag the highlight annotations created by
+/// [PdfPage::highlight_search_results()], so that they can be identified and removed later.
+const SEARCH_HIGHLIGHT_KEY: &str = "PdfiumRenderSearchHighlight";
+
+/// The resolution, in dots per inch, at which [PdfPage::fingerprint()] renders a page.
+const FINGERPRINT_DPI: f32 = 18.0;
+
+/// The number of low-order bits discarded from each gray level when [PdfPage::fingerprint()]
+/// hashes a rendered page, so that minor anti-aliasing differences do not change the fingerprint.
+const FINGERPRINT_GRAY_LEVEL_SHIFT: u8 = 4;
+
+/// The initial value of the 64-bit FNV-1a hash used by [PdfPage::fingerprint()].
+const FINGERPRINT_HASH_SEED: u64 = 0xcbf2_9ce4_8422_2325;
 
 /// The internal coordinate system inside a [PdfDocument] is measured in Points, a
 /// device-independent unit equal to 1/72 inches, roughly 0.358 mm. Points are converted to pixels
@@ -358,6 +393,64 @@ pub enum PdfPageContentRegenerationStrategy {
     Manual,
 }
 
+/// The result of analysing a [PdfPage] to determine whether it appears to be a scanned image
+/// of a physical page, or a digitally created page. Generated by calling [PdfPage::scan_analysis()].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfPageScanAnalysis {
+    is_scanned: bool,
+    image_horizontal_dpi: Option<f32>,
+    image_vertical_dpi: Option<f32>,
+    recommended_render_dpi: f32,
+}
+
+impl PdfPageScanAnalysis {
+    /// The proportion of the page area that a single image must cover for the page
+    /// to be considered a scanned page.
+    const SCANNED_PAGE_COVERAGE_THRESHOLD: f32 = 0.9;
+
+    /// The render resolution recommended for OCR when a page does not appear to be scanned,
+    /// or when the effective resolution of the scanned image cannot be determined.
+    const DEFAULT_OCR_DPI: f32 = 300.0;
+
+    /// The minimum render resolution recommended for OCR.
+    const MINIMUM_OCR_DPI: f32 = 150.0;
+
+    /// The maximum render resolution recommended for OCR.
+    const MAXIMUM_OCR_DPI: f32 = 600.0;
+
+    /// Returns `true` if the analysed [PdfPage] is dominated by a single image covering
+    /// all or nearly all of the page, suggesting the page is a scan of a physical page.
+    #[inline]
+    pub fn is_scanned(&self) -> bool {
+        self.is_scanned
+    }
+
+    /// Returns the effective horizontal resolution, in dots per inch, of the image dominating
+    /// the analysed [PdfPage], if the page appears to be scanned.
+    #[inline]
+    pub fn image_horizontal_dpi(&self) -> Option<f32> {
+        self.image_horizontal_dpi
+    }
+
+    /// Returns the effective vertical resolution, in dots per inch, of the image dominating
+    /// the analysed [PdfPage], if the page appears to be scanned.
+    #[inline]
+    pub fn image_vertical_dpi(&self) -> Option<f32> {
+        self.image_vertical_dpi
+    }
+
+    /// Returns the resolution, in dots per inch, at which the analysed [PdfPage] should be
+    /// rendered for the purposes of optical character recognition.
+    ///
+    /// For scanned pages, this is the native resolution of the scanned image, so that the image
+    /// is neither upscaled (introducing blur) nor downscaled (losing detail), clamped to a
+    /// sensible range of 150 - 600 dpi. For digital pages, this is 300 dpi.
+    #[inline]
+    pub fn recommended_render_dpi(&self) -> f32 {
+        self.recommended_render_dpi
+    }
+}
+
 /// A single page in a [PdfDocument].
 ///
 /// In addition to its own intrinsic properties, a [PdfPage] serves as the entry point
@@ -380,6 +473,7 @@ pub struct PdfPage<'a> {
     boundaries: PdfPageBoundaries<'a>,
     links: PdfPageLinks<'a>,
     objects: PdfPageObjects<'a>,
+    cached_page: Option<Rc<PdfCachedPage>>,
 }
 
 impl<'a> PdfPage<'a> {
@@ -404,6 +498,7 @@ impl<'a> PdfPage<'a> {
             boundaries: PdfPageBoundaries::from_pdfium(handle, document.bindings()),
             links: PdfPageLinks::from_pdfium(handle, *document.handle(), document.bindings()),
             objects: PdfPageObjects::from_pdfium(handle, *document.handle(), document.bindings()),
+            cached_page: None,
         };
 
         // Make sure the default content regeneration strategy is applied to child containers.
@@ -419,6 +514,14 @@ impl<'a> PdfPage<'a> {
         &self.handle
     }
 
+    /// Associates this [PdfPage] with the given entry in its document's page cache, if any.
+    /// A cached page's handle is owned by the cache entry, so it is not closed when this
+    /// [PdfPage] is dropped.
+    #[inline]
+    pub(crate) fn set_cached_page(&mut self, cached_page: Option<Rc<PdfCachedPage>>) {
+        self.cached_page = cached_page;
+    }
+
     /// Returns the [PdfDocument] containing this [PdfPage].
     #[inline]
     pub fn document(&self) -> &'a PdfDocument<'a> {
@@ -462,6 +565,85 @@ impl<'a> PdfPage<'a> {
         )
     }
 
+    /// Returns the bounds of the content actually drawn on this [PdfPage], i.e. the smallest
+    /// rectangle enclosing every page object on the page, clipped to the visible area of the page.
+    /// Returns `None` if no page objects are visible on the page.
+    ///
+    /// The content bounding box differs from both the page's MediaBox and CropBox, and from
+    /// the page's BBox as returned by `PdfPageBoundaries::bounding()`, all of which describe
+    /// the declared extent of the page rather than the extent of its content. Page objects
+    /// are measured irrespective of their color, so a white rectangle or a full-page scanned
+    /// image will extend the content bounding box even though it may appear blank.
+    /// Annotations are not included.
+    pub fn content_bounding_box(&self) -> Result<Option<PdfRect>, PdfiumError> {
+        let visible = self.boundaries().bounding()?.bounds;
+
+        let mut result: Option<PdfRect> = None;
+
+        for object in self.objects().iter() {
+            let bounds = match object.bounds() {
+                Ok(bounds) => bounds,
+                Err(_) => continue,
+            };
+
+            result = Some(match result {
+                Some(rect) => PdfRect::new(
+                    PdfPoints::new(rect.bottom.value.min(bounds.bottom.value)),
+                    PdfPoints::new(rect.left.value.min(bounds.left.value)),
+                    PdfPoints::new(rect.top.value.max(bounds.top.value)),
+                    PdfPoints::new(rect.right.value.max(bounds.right.value)),
+                ),
+                None => bounds,
+            });
+        }
+
+        Ok(result
+            .map(|rect| {
+                PdfRect::new(
+                    PdfPoints::new(rect.bottom.value.max(visible.bottom.value)),
+                    PdfPoints::new(rect.left.value.max(visible.left.value)),
+                    PdfPoints::new(rect.top.value.min(visible.top.value)),
+                    PdfPoints::new(rect.right.value.min(visible.right.value)),
+                )
+            })
+            .filter(|rect| {
+                rect.right.value > rect.left.value && rect.top.value > rect.bottom.value
+            }))
+    }
+
+    /// Sets the CropBox of this [PdfPage] to the extent of the content drawn on the page,
+    /// as returned by [PdfPage::content_bounding_box()], expanded by the given margin on all sides.
+    /// This is a convenient way of trimming surrounding whitespace from a page.
+    ///
+    /// The resulting CropBox is limited to the page's MediaBox, so a margin that would extend
+    /// beyond the edges of the page is reduced accordingly. If the page has no explicit
+    /// MediaBox, the page's current visible area is used as the limit instead.
+    /// Returns [PdfiumError::NoContentOnPage] if the page has no visible content, or
+    /// [PdfiumError::NegativeMargin] if the given margin is negative.
+    pub fn auto_crop(&mut self, margin: PdfPoints) -> Result<(), PdfiumError> {
+        if margin.value < 0.0 {
+            return Err(PdfiumError::NegativeMargin);
+        }
+
+        let content = self
+            .content_bounding_box()?
+            .ok_or(PdfiumError::NoContentOnPage)?;
+
+        let limit = match self.boundaries().media() {
+            Ok(media) => media.bounds,
+            Err(_) => self.boundaries().bounding()?.bounds,
+        };
+
+        let crop = PdfRect::new(
+            PdfPoints::new((content.bottom.value - margin.value).max(limit.bottom.value)),
+            PdfPoints::new((content.left.value - margin.value).max(limit.left.value)),
+            PdfPoints::new((content.top.value + margin.value).min(limit.top.value)),
+            PdfPoints::new((content.right.value + margin.value).min(limit.right.value)),
+        );
+
+        self.boundaries_mut().set_crop(crop)
+    }
+
     /// Returns [PdfPageOrientation::Landscape] if the width of this [PdfPage]
     /// is greater than its height; otherwise returns [PdfPageOrientation::Portrait].
     #[inline]
@@ -483,12 +665,21 @@ impl<'a> PdfPage<'a> {
 
     /// Returns any intrinsic rotation encoded into this document indicating a rotation
     /// should be applied to this [PdfPage] during rendering.
+    ///
+    /// The `/Rotate` entry is an inheritable page attribute, so if this [PdfPage] does not
+    /// specify a rotation itself, Pdfium resolves it by walking up the page tree and using
+    /// the value from the nearest ancestor `/Pages` node that does. Pdfium also normalizes
+    /// negative values, such as -90, to their positive equivalents.
     #[inline]
     pub fn rotation(&self) -> Result<PdfBitmapRotation, PdfiumError> {
         PdfBitmapRotation::from_pdfium(self.bindings().FPDFPage_GetRotation(self.handle))
     }
 
     /// Sets the intrinsic rotation that should be applied to this [PdfPage] during rendering.
+    ///
+    /// The rotation is written to the `/Rotate` entry of this [PdfPage] itself, overriding
+    /// any rotation inherited from the page tree. Other pages are unaffected. The new rotation
+    /// is retained when the containing [PdfDocument] is saved.
     #[inline]
     pub fn set_rotation(&mut self, rotation: PdfBitmapRotation) {
         self.bindings()
@@ -508,6 +699,142 @@ impl<'a> PdfPage<'a> {
         PdfPagePaperSize::from_points(self.width(), self.height())
     }
 
+    /// Analyses the page objects on this [PdfPage] to determine whether the page appears to be
+    /// a scanned image of a physical page, returning the effective resolution of the scanned image
+    /// and a recommended render resolution for optical character recognition.
+    ///
+    /// A page is considered to be scanned if a single image object covers at least 90% of the
+    /// area of the page. The effective resolution of the image is its pixel dimensions divided by
+    /// its displayed dimensions on the page in inches.
+    pub fn scan_analysis(&self) -> PdfPageScanAnalysis {
+        let page_area = self.width().value * self.height().value;
+
+        let mut largest_image: Option<(f32, f32, f32)> = None;
+
+        if page_area > 0.0 {
+            for object in self.objects().iter() {
+                if let Some(image) = object.as_image_object() {
+                    let area = match image.bounds() {
+                        Ok(bounds) => bounds.width().value * bounds.height().value,
+                        Err(_) => continue,
+                    };
+
+                    let is_largest_image = match largest_image {
+                        Some((largest_area, _, _)) => area > largest_area,
+                        None => true,
+                    };
+
+                    if is_largest_image {
+                        let (horizontal_dpi, vertical_dpi) =
+                            match (image.horizontal_dpi(), image.vertical_dpi()) {
+                                (Ok(horizontal_dpi), Ok(vertical_dpi)) => {
+                                    (horizontal_dpi, vertical_dpi)
+                                }
+                                _ => (0.0, 0.0),
+                            };
+
+                        largest_image = Some((area, horizontal_dpi, vertical_dpi));
+                    }
+                }
+            }
+        }
+
+        match largest_image {
+            Some((area, horizontal_dpi, vertical_dpi))
+                if area / page_area >= PdfPageScanAnalysis::SCANNED_PAGE_COVERAGE_THRESHOLD =>
+            {
+                let native_dpi = horizontal_dpi.max(vertical_dpi);
+
+                PdfPageScanAnalysis {
+                    is_scanned: true,
+                    image_horizontal_dpi: (horizontal_dpi > 0.0).then_some(horizontal_dpi),
+                    image_vertical_dpi: (vertical_dpi > 0.0).then_some(vertical_dpi),
+                    recommended_render_dpi: if native_dpi > 0.0 {
+                        native_dpi.clamp(
+                            PdfPageScanAnalysis::MINIMUM_OCR_DPI,
+                            PdfPageScanAnalysis::MAXIMUM_OCR_DPI,
+                        )
+                    } else {
+                        PdfPageScanAnalysis::DEFAULT_OCR_DPI
+                    },
+                }
+            }
+            _ => PdfPageScanAnalysis {
+                is_scanned: false,
+                image_horizontal_dpi: None,
+                image_vertical_dpi: None,
+                recommended_render_dpi: PdfPageScanAnalysis::DEFAULT_OCR_DPI,
+            },
+        }
+    }
+
+    /// Returns `true` if this [PdfPage] contains text that is present in the page content
+    /// but not visible when the page is rendered.
+    ///
+    /// Use the [PdfPage::hidden_text()] function to retrieve the hidden text for inspection.
+    #[inline]
+    pub fn has_hidden_text(&self) -> bool {
+        self.hidden_text().is_some()
+    }
+
+    /// Returns the text contained in all text objects on this [PdfPage] that are not visible
+    /// when the page is rendered, concatenated in page content order and separated by spaces,
+    /// or `None` if the page contains no hidden text.
+    ///
+    /// A text object is considered hidden if it uses an invisible text render mode, or if every
+    /// part of the text that would be painted - its fill, its stroke, or both, depending on the
+    /// render mode - is fully transparent. Hidden text is legitimately used for the text layer of
+    /// scanned and OCR-processed documents, but can also indicate keyword stuffing or other
+    /// attempts to mislead text extraction, so it can be a useful signal when screening documents.
+    ///
+    /// Only top-level text objects on the page are examined. Text that is obscured by other
+    /// page objects, clipped, or positioned outside the visible area of the page is not detected.
+    pub fn hidden_text(&self) -> Option<String> {
+        let is_transparent = |color: Result<PdfColor, PdfiumError>| {
+            color.map(|color| color.alpha() == 0).unwrap_or(false)
+        };
+
+        let hidden_text =
+            self.objects()
+                .iter()
+                .filter_map(|object| {
+                    let text_object = object.as_text_object()?;
+
+                    let is_hidden = match text_object.render_mode() {
+                        PdfPageTextRenderMode::Invisible
+                        | PdfPageTextRenderMode::InvisibleClipping => true,
+                        PdfPageTextRenderMode::FilledUnstroked
+                        | PdfPageTextRenderMode::FilledUnstrokedClipping => {
+                            is_transparent(text_object.fill_color())
+                        }
+                        PdfPageTextRenderMode::StrokedUnfilled
+                        | PdfPageTextRenderMode::StrokedUnfilledClipping => {
+                            is_transparent(text_object.stroke_color())
+                        }
+                        PdfPageTextRenderMode::FilledThenStroked
+                        | PdfPageTextRenderMode::FilledThenStrokedClipping => {
+                            is_transparent(text_object.fill_color())
+                                && is_transparent(text_object.stroke_color())
+                        }
+                        PdfPageTextRenderMode::Unknown => false,
+                    };
+
+                    if is_hidden {
+                        Some(text_object.text())
+                    } else {
+                        None
+                    }
+                })
+                .filter(|text| !text.trim().is_empty())
+                .collect::<Vec<_>>();
+
+        if hidden_text.is_empty() {
+            None
+        } else {
+            Some(hidden_text.join(" "))
+        }
+    }
+
     /// Returns `true` if this [PdfPage] contains an embedded thumbnail.
     ///
     /// Embedded thumbnails can be generated as a courtesy by PDF generators to save PDF consumers
@@ -532,6 +859,36 @@ impl<'a> PdfPage<'a> {
             > 0
     }
 
+    /// Returns the embedded thumbnail for this [PdfPage] if its width and height are both at
+    /// least the given number of pixels, or `None` if the page has no embedded thumbnail or
+    /// the embedded thumbnail is smaller than this.
+    ///
+    /// Many PDF generators embed tiny placeholder thumbnails (sometimes as small as 1 x 1 pixels)
+    /// that are of little use for display purposes. This function can be used to decide whether
+    /// to use the embedded thumbnail or fall back to rendering a new thumbnail instead:
+    ///
+    /// ```
+    /// # use pdfium_render::prelude::*;
+    /// #
+    /// # fn example(page: &PdfPage) -> Result<(), PdfiumError> {
+    ///     let thumbnail = match page.embedded_thumbnail_if_at_least(64) {
+    ///         Some(thumbnail) => thumbnail,
+    ///         None => page.render_with_config(&PdfRenderConfig::new().thumbnail(128))?,
+    ///     };
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn embedded_thumbnail_if_at_least(&self, min_pixels: Pixels) -> Option<PdfBitmap> {
+        // Pdfium does not expose the dimensions of the thumbnail image directly, so we must
+        // decode the thumbnail to measure it. We return the decoded bitmap so the caller
+        // need not decode it a second time.
+
+        self.embedded_thumbnail()
+            .ok()
+            .filter(|thumbnail| thumbnail.width() >= min_pixels && thumbnail.height() >= min_pixels)
+    }
+
     /// Returns the embedded thumbnail for this [PdfPage], if any.
     ///
     /// Embedded thumbnails can be generated as a courtesy by PDF generators to save PDF consumers
@@ -559,6 +916,14 @@ impl<'a> PdfPage<'a> {
     }
 
     /// Returns the collection of text boxes contained within this [PdfPage].
+    ///
+    /// Pdfium's text page for this [PdfPage] is loaded once when this function is called,
+    /// and is kept open for the lifetime of the returned [PdfPageText] object. All text
+    /// operations performed through the returned object - retrieving characters and their
+    /// bounding boxes, text segments, lines, and so on - share the same loaded text page,
+    /// so it is considerably faster to call this function once and reuse the result than
+    /// to call it once per operation. The text page is closed when the returned [PdfPageText]
+    /// object is dropped.
     pub fn text(&self) -> Result<PdfPageText, PdfiumError> {
         if self.regeneration_strategy == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
             && self.is_content_regeneration_required
@@ -584,6 +949,110 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns an identifier derived from both the text content and the rendered appearance
+    /// of this [PdfPage], suitable for detecting duplicate or near-identical pages, such as
+    /// repeated boilerplate pages, within or across documents.
+    ///
+    /// The fingerprint combines a hash of the page's text, with runs of whitespace collapsed,
+    /// and a hash of the page rendered at a fixed low resolution in grayscale with reduced
+    /// gray-level precision. Hashing the text alone would treat all image-only pages as
+    /// identical, while hashing the rendering alone would be sensitive to trivial differences
+    /// in the rendered output; combining both is more robust than either. Annotations and
+    /// form data are not included.
+    ///
+    /// The fingerprint is calculated using a fixed hash function and is stable across runs,
+    /// so it can be stored and compared later. Since the rendered portion depends on Pdfium's
+    /// rasterizer, fingerprints calculated using different Pdfium versions may differ.
+    pub fn fingerprint(&self) -> Result<u64, PdfiumError> {
+        let text = self.text()?.all();
+
+        let mut hash = fingerprint_hash(
+            FINGERPRINT_HASH_SEED,
+            text.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .as_bytes(),
+        );
+
+        let bitmap = self.render_with_config(
+            &PdfRenderConfig::new()
+                .scale_page_by_factor(FINGERPRINT_DPI / 72.0)
+                .set_format(PdfBitmapFormat::BGRA)
+                .set_reverse_byte_order(true)
+                .clear_before_rendering(true)
+                .set_clear_color(PdfColor::SOLID_WHITE)
+                .set_text_smoothing(false)
+                .set_image_smoothing(false)
+                .set_path_smoothing(false)
+                .render_annotations(false)
+                .render_form_data(false),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        hash = fingerprint_hash(hash, &(width as u32).to_le_bytes());
+        hash = fingerprint_hash(hash, &(height as u32).to_le_bytes());
+
+        let bytes = bitmap.as_bytes();
+
+        // Rows in the bitmap buffer may be padded, so each row is hashed separately
+        // to exclude the padding bytes. Pixels are in RGBA order, since we asked
+        // Pdfium to reverse its byte order.
+
+        if let Some(stride) = bytes.len().checked_div(height).filter(|stride| *stride > 0) {
+            for row in bytes.chunks_exact(stride) {
+                let gray = row[..width * 4]
+                    .chunks_exact(4)
+                    .map(|pixel| {
+                        let luma =
+                            (pixel[0] as u32 * 299 + pixel[1] as u32 * 587 + pixel[2] as u32 * 114)
+                                / 1000;
+
+                        (luma as u8) >> FINGERPRINT_GRAY_LEVEL_SHIFT
+                    })
+                    .collect::<Vec<_>>();
+
+                hash = fingerprint_hash(hash, &gray);
+            }
+        }
+
+        Ok(hash)
+    }
+
+    /// Returns the element in this [PdfPage]'s logical structure tree that owns the given
+    /// page object, if any.
+    ///
+    /// The page object is linked to the structure tree by the marked-content identifier
+    /// in its `MCID` content mark. This function returns `None` if the document is not tagged,
+    /// if the page object carries no marked-content identifier, or if no structure element
+    /// on this page directly references the identifier. Structure elements that reference
+    /// their content using an array of marked-content references are not matched.
+    pub fn struct_element_for_object(
+        &self,
+        object: &PdfPageObject,
+    ) -> Option<PdfStructElement<'_>> {
+        let bindings = self.bindings();
+
+        let object_handle = *object.get_object_handle();
+
+        let marked_content_id =
+            (0..bindings.FPDFPageObj_CountMarks(object_handle)).find_map(|index| {
+                let mark = bindings.FPDFPageObj_GetMark(object_handle, index as c_ulong);
+
+                let mut value: c_int = 0;
+
+                (!mark.is_null()
+                    && bindings.is_true(
+                        bindings.FPDFPageObjMark_GetParamIntValue(mark, "MCID", &mut value),
+                    ))
+                .then_some(value)
+            })?;
+
+        PdfStructElement::find_for_marked_content_id(self.handle, marked_content_id, bindings)
+    }
+
     /// Returns an immutable collection of the annotations that have been added to this [PdfPage].
     pub fn annotations(&self) -> &PdfPageAnnotations<'a> {
         if self.regeneration_strategy == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
@@ -616,6 +1085,180 @@ impl<'a> PdfPage<'a> {
         &mut self.annotations
     }
 
+    /// Searches the text of this [PdfPage] for the given query, creating a highlight annotation
+    /// in the given color over each match. Returns the number of matches highlighted.
+    ///
+    /// The search is case-insensitive and matches partial words. Each highlight annotation
+    /// covers the rectangular areas occupied by the characters of its match, so a match that
+    /// wraps across lines produces a single annotation spanning both lines.
+    ///
+    /// The created annotations are tagged so they can be recognized later. Any highlights
+    /// created by a previous call to this function are removed before searching, so calling
+    /// this function repeatedly does not accumulate duplicate highlights. To remove the
+    /// highlights without creating new ones, use [PdfPage::clear_search_result_highlights()].
+    pub fn highlight_search_results(
+        &mut self,
+        query: &str,
+        color: PdfColor,
+    ) -> Result<usize, PdfiumError> {
+        self.clear_search_result_highlights()?;
+
+        let bindings = self.bindings();
+
+        // Collect the quad points for every match before creating any annotations,
+        // so that the text page is not inspected while the page is being modified.
+
+        let matches = {
+            let text = self.text()?;
+
+            let search = text.search(query, &PdfSearchOptions::new(), None)?;
+
+            let mut matches = Vec::new();
+
+            while let Some(chars) = search.find_next() {
+                let count = bindings.FPDFText_CountRects(
+                    *text.handle(),
+                    chars.first_char_index() as c_int,
+                    chars.len() as c_int,
+                );
+
+                let quads = (0..count)
+                    .filter_map(|index| {
+                        let mut left: c_double = 0.0;
+
+                        let mut top: c_double = 0.0;
+
+                        let mut right: c_double = 0.0;
+
+                        let mut bottom: c_double = 0.0;
+
+                        bindings
+                            .is_true(bindings.FPDFText_GetRect(
+                                *text.handle(),
+                                index,
+                                &mut left,
+                                &mut top,
+                                &mut right,
+                                &mut bottom,
+                            ))
+                            .then_some(FS_QUADPOINTSF {
+                                x1: left as f32,
+                                y1: top as f32,
+                                x2: right as f32,
+                                y2: top as f32,
+                                x3: left as f32,
+                                y3: bottom as f32,
+                                x4: right as f32,
+                                y4: bottom as f32,
+                            })
+                    })
+                    .collect::<Vec<_>>();
+
+                if !quads.is_empty() {
+                    matches.push(quads);
+                }
+            }
+
+            matches
+        };
+
+        for quads in matches.iter() {
+            let annotation = bindings
+                .FPDFPage_CreateAnnot(self.handle, FPDF_ANNOT_HIGHLIGHT as FPDF_ANNOTATION_SUBTYPE);
+
+            if annotation.is_null() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    bindings
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ));
+            }
+
+            let rect = quads.iter().skip(1).fold(
+                FS_RECTF {
+                    left: quads[0].x1,
+                    top: quads[0].y1,
+                    right: quads[0].x4,
+                    bottom: quads[0].y4,
+                },
+                |rect, quad| FS_RECTF {
+                    left: rect.left.min(quad.x1),
+                    top: rect.top.max(quad.y1),
+                    right: rect.right.max(quad.x4),
+                    bottom: rect.bottom.min(quad.y4),
+                },
+            );
+
+            let is_created = quads.iter().all(|quad| {
+                bindings.is_true(bindings.FPDFAnnot_AppendAttachmentPoints(annotation, quad))
+            }) && bindings.is_true(bindings.FPDFAnnot_SetRect(annotation, &rect))
+                && bindings.is_true(bindings.FPDFAnnot_SetColor(
+                    annotation,
+                    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+                    color.red() as u32,
+                    color.green() as u32,
+                    color.blue() as u32,
+                    color.alpha() as u32,
+                ))
+                && bindings.is_true(bindings.FPDFAnnot_SetStringValue_str(
+                    annotation,
+                    SEARCH_HIGHLIGHT_KEY,
+                    query,
+                ));
+
+            bindings.FPDFPage_CloseAnnot(annotation);
+
+            if !is_created {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    bindings
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ));
+            }
+        }
+
+        Ok(matches.len())
+    }
+
+    /// Removes all highlight annotations previously created by
+    /// [PdfPage::highlight_search_results()] from this [PdfPage], returning the number of
+    /// annotations removed. Other annotations are left untouched.
+    pub fn clear_search_result_highlights(&mut self) -> Result<usize, PdfiumError> {
+        let bindings = self.bindings();
+
+        let mut removed = 0;
+
+        // Iterate in reverse order, so that removing an annotation does not change
+        // the indices of the annotations still to be inspected.
+
+        for index in (0..bindings.FPDFPage_GetAnnotCount(self.handle)).rev() {
+            let annotation = bindings.FPDFPage_GetAnnot(self.handle, index);
+
+            if annotation.is_null() {
+                continue;
+            }
+
+            let is_search_highlight =
+                bindings.is_true(bindings.FPDFAnnot_HasKey(annotation, SEARCH_HIGHLIGHT_KEY));
+
+            bindings.FPDFPage_CloseAnnot(annotation);
+
+            if is_search_highlight {
+                if !bindings.is_true(bindings.FPDFPage_RemoveAnnot(self.handle, index)) {
+                    return Err(PdfiumError::PdfiumLibraryInternalError(
+                        bindings
+                            .get_pdfium_last_error()
+                            .unwrap_or(PdfiumInternalError::Unknown),
+                    ));
+                }
+
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
     /// Returns an immutable collection of the bounding boxes defining the extents of this [PdfPage].
     #[inline]
     pub fn boundaries(&self) -> &PdfPageBoundaries<'a> {
@@ -751,79 +1394,61 @@ impl<'a> PdfPage<'a> {
         Ok(bitmap)
     }
 
-    /// Renders this [PdfPage] into the given [PdfBitmap] using the given the given pixel dimensions
-    /// and page rotation.
+    /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, page rotation settings,
+    /// and rendering options configured in the given [PdfRenderConfig], allowing rendering
+    /// to be abandoned part-way through.
     ///
-    /// It is the responsibility of the caller to ensure the given pixel width and height
-    /// correctly maintain the page's aspect ratio. The size of the buffer backing the given bitmap
-    /// must be sufficiently large to hold the rendered image or an error will be returned.
+    /// Pdfium calls the given `should_pause` callback periodically while rendering the page.
+    /// If the callback returns `true`, rendering stops and the partially rendered bitmap
+    /// is returned; otherwise rendering continues until the page is complete. This makes it
+    /// possible to cancel rendering of a large or complex page, for instance when a request
+    /// times out. The callback is not informed when rendering completes, so callers that need
+    /// to distinguish a cancelled render from a complete one should record whether the callback
+    /// ever returned `true`.
     ///
-    /// See also [PdfPage::render_into_bitmap_with_config()], which calculates the correct pixel dimensions,
-    /// rotation settings, and rendering options to apply from a [PdfRenderConfig] object.
-    pub fn render_into_bitmap(
-        &self,
-        bitmap: &mut PdfBitmap,
-        width: u16,
-        height: u16,
-        rotation: Option<PdfBitmapRotation>,
-    ) -> Result<(), PdfiumError> {
-        let mut config = PdfRenderConfig::new()
-            .set_target_width(width)
-            .set_target_height(height);
-
-        if let Some(rotation) = rotation {
-            config = config.rotate(rotation, true);
-        }
-
-        self.render_into_bitmap_with_config(bitmap, &config)
-    }
-
-    /// Renders this [PdfPage] into the given [PdfBitmap] using pixel dimensions, page rotation settings,
-    /// and rendering options configured in the given [PdfRenderConfig].
+    /// Pdfium's progressive rendering pipeline does not support custom transformation matrices,
+    /// so any transformation applied to the given [PdfRenderConfig] is ignored. User-supplied
+    /// form data is only rendered if rendering was not cancelled. An error is returned if Pdfium
+    /// reports that rendering failed.
     ///
-    /// The size of the buffer backing the given bitmap must be sufficiently large to hold the
-    /// rendered image or an error will be returned.
-    #[inline]
-    pub fn render_into_bitmap_with_config(
+    /// Pause callbacks cannot be passed to Pdfium when compiling to WASM, so on that platform
+    /// the callback is never called and the page is always rendered in full.
+    pub fn render_progressive(
         &self,
-        bitmap: &mut PdfBitmap,
         config: &PdfRenderConfig,
-    ) -> Result<(), PdfiumError> {
-        self.render_into_bitmap_with_settings(bitmap, config.apply_to_page(self))
-    }
+        mut should_pause: impl FnMut() -> bool,
+    ) -> Result<PdfBitmap<'_>, PdfiumError> {
+        let settings = config.apply_to_page(self);
+
+        let mut bitmap = PdfBitmap::empty(
+            settings.width as u16,
+            settings.height as u16,
+            PdfBitmapFormat::from_pdfium(settings.format as u32)
+                .unwrap_or_else(|_| PdfBitmapFormat::default()),
+            self.bindings(),
+        )?;
 
-    /// Renders this [PdfPage] into the given [PdfBitmap] using the given [PdfRenderSettings].
-    /// The size of the buffer backing the given bitmap must be sufficiently large to hold
-    /// the rendered image or an error will be returned.
-    pub(crate) fn render_into_bitmap_with_settings(
-        &self,
-        bitmap: &mut PdfBitmap,
-        settings: PdfRenderSettings,
-    ) -> Result<(), PdfiumError> {
         let bitmap_handle = *bitmap.handle();
 
-        if settings.do_clear_bitmap_before_rendering {
-            // Clear the bitmap buffer by setting every pixel to a known color.
+        self.clear_bitmap_with_settings(&bitmap, &settings)?;
 
-            self.bindings().FPDFBitmap_FillRect(
+        let mut pause = get_pdfium_pause_from_callback(&mut should_pause);
+
+        let status = if let Some(color_scheme) = settings.color_scheme.as_ref() {
+            self.bindings().FPDF_RenderPageBitmapWithColorScheme_Start(
                 bitmap_handle,
+                self.handle,
                 0,
                 0,
                 settings.width,
                 settings.height,
-                settings.clear_color,
-            );
-
-            if let Some(error) = self.bindings().get_pdfium_last_error() {
-                return Err(PdfiumError::PdfiumLibraryInternalError(error));
-            }
-        }
-
-        if settings.do_render_form_data {
-            // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
-            // (Custom transforms cannot be applied to the rendering of form fields.)
-
-            self.bindings().FPDF_RenderPageBitmap(
+                settings.rotate,
+                settings.render_flags,
+                color_scheme,
+                pause.as_ifsdk_pause_mut_ptr(),
+            )
+        } else {
+            self.bindings().FPDF_RenderPageBitmap_Start(
                 bitmap_handle,
                 self.handle,
                 0,
@@ -832,57 +1457,628 @@ impl<'a> PdfPage<'a> {
                 settings.height,
                 settings.rotate,
                 settings.render_flags,
-            );
+                pause.as_ifsdk_pause_mut_ptr(),
+            )
+        };
 
-            if let Some(error) = self.bindings().get_pdfium_last_error() {
-                return Err(PdfiumError::PdfiumLibraryInternalError(error));
-            }
+        // Pdfium only suspends rendering when the pause callback asks it to, so a suspended
+        // render has been cancelled by the caller. Closing the render discards Pdfium's
+        // progressive rendering state, leaving the partially rendered bitmap in place.
 
-            if let Some(form) = self.document().form() {
-                // Render user-supplied form data, if any, as an overlay on top of the page.
+        let is_cancelled = status == FPDF_RENDER_TOBECONTINUED as c_int;
 
-                if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
-                    for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
-                        self.bindings().FPDF_SetFormFieldHighlightColor(
-                            *form.handle(),
-                            *form_field_type,
-                            *color,
-                        );
+        self.bindings().FPDF_RenderPage_Close(self.handle);
 
-                        self.bindings()
-                            .FPDF_SetFormFieldHighlightAlpha(*form.handle(), *alpha);
-                    }
-                }
+        if status == FPDF_RENDER_FAILED as c_int {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
 
-                self.bindings().FPDF_FFLDraw(
-                    *form.handle(),
-                    bitmap_handle,
-                    self.handle,
-                    0,
-                    0,
-                    settings.width,
-                    settings.height,
-                    settings.rotate,
-                    settings.render_flags,
-                );
+        if let Some(error) = self.bindings().get_pdfium_last_error() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(error));
+        }
 
-                if let Some(error) = self.bindings().get_pdfium_last_error() {
-                    return Err(PdfiumError::PdfiumLibraryInternalError(error));
-                }
-            }
-        } else {
-            // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
+        if settings.do_render_form_data && !is_cancelled {
+            // Render user-supplied form data, if any, as an overlay on top of the page.
 
-            self.bindings().FPDF_RenderPageBitmapWithMatrix(
-                bitmap_handle,
-                self.handle,
-                &settings.matrix,
-                &settings.clipping,
-                settings.render_flags,
-            );
+            self.render_form_data_into_bitmap_with_settings(&mut bitmap, &settings)?;
+        }
 
-            if let Some(error) = self.bindings().get_pdfium_last_error() {
-                return Err(PdfiumError::PdfiumLibraryInternalError(error));
+        if settings.do_premultiply_alpha {
+            bitmap.premultiply_alpha()?;
+        }
+
+        Ok(bitmap)
+    }
+
+    /// Renders this [PdfPage] using the given [PdfRenderConfig], streaming the PNG-encoded
+    /// result directly to the given writer.
+    ///
+    /// This avoids materializing a separate `DynamicImage` copy of the rendered pixels or
+    /// a separate buffer of encoded bytes, which can reduce peak memory usage substantially
+    /// when converting many pages to image files. See [PdfBitmap::write_png()].
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn render_to_png_writer<W: Write>(
+        &self,
+        writer: &mut W,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        self.render_with_config(config)?.write_png(writer)
+    }
+
+    /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, page rotation settings,
+    /// and rendering options configured in the given [PdfRenderConfig], returning the bitmap
+    /// together with a [PdfPageTextHitMap] mapping the pixels of the bitmap to the indices
+    /// of the characters drawn at those pixels.
+    ///
+    /// The hit map allows the character under the mouse pointer to be looked up cheaply
+    /// when implementing interactive text selection, without converting between pixel and
+    /// page coordinates on every mouse move. Character bounds are projected using the output
+    /// dimensions and rotation of the given [PdfRenderConfig]; any custom transformation matrix
+    /// or clipping applied by the [PdfRenderConfig] is not reflected in the hit map.
+    pub fn render_with_text_map(
+        &self,
+        config: &PdfRenderConfig,
+    ) -> Result<(PdfBitmap<'_>, PdfPageTextHitMap), PdfiumError> {
+        let bitmap = self.render_with_config(config)?;
+
+        let map = PdfPageTextHitMap::new(
+            self.handle,
+            &self.text()?,
+            &config.apply_to_page(self),
+            self.bindings(),
+        );
+
+        Ok((bitmap, map))
+    }
+
+    /// Renders each annotation of the given [PdfPageAnnotationType] on this [PdfPage] onto its
+    /// own transparent bitmap, using pixel dimensions, page rotation settings, and rendering
+    /// options configured in the given [PdfRenderConfig].
+    ///
+    /// Each returned [PdfPageAnnotationOverlay] covers only the bounds of its annotation, and
+    /// records where it should be drawn over a bitmap rendered from this page using the same
+    /// [PdfRenderConfig]. This allows annotations to be layered over the rendered page as
+    /// independent overlays that can be shown or hidden individually. It is generally
+    /// desirable to disable rendering of annotations when rendering the page itself, by calling
+    /// `PdfRenderConfig::render_annotations(false)`.
+    ///
+    /// Pdfium cannot render a single annotation in isolation, so while each annotation is
+    /// rendered, the page content of this page is temporarily removed and all other
+    /// annotations are temporarily hidden. Both are restored before this function returns.
+    /// Only the output dimensions and rotation of the [PdfRenderConfig] are taken into
+    /// account; an error will be returned if the [PdfRenderConfig] applies a custom
+    /// transformation matrix, clipping region, or source region.
+    pub fn render_annotation_overlays(
+        &self,
+        annotation_type: PdfPageAnnotationType,
+        config: &PdfRenderConfig,
+    ) -> Result<Vec<PdfPageAnnotationOverlay<'a>>, PdfiumError> {
+        if config.has_custom_transform() {
+            return Err(PdfiumError::AnnotationOverlayTransformNotSupported);
+        }
+
+        let indices = self
+            .annotations()
+            .iter()
+            .enumerate()
+            .filter(|(_, annotation)| annotation.annotation_type() == annotation_type)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        if indices.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let settings = config.apply_to_page(self);
+
+        let bindings = self.bindings();
+
+        // Remove the page content, leaving only the page's annotations. The page content is
+        // not regenerated, so re-inserting the removed objects in their original order once
+        // rendering is complete leaves the page unchanged.
+
+        let removed_objects = (0..bindings.FPDFPage_CountObjects(self.handle))
+            .map(|index| bindings.FPDFPage_GetObject(self.handle, index))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .filter(|object| bindings.is_true(bindings.FPDFPage_RemoveObject(self.handle, *object)))
+            .collect::<Vec<_>>();
+
+        let annotations = (0..bindings.FPDFPage_GetAnnotCount(self.handle))
+            .map(|index| {
+                let annotation = bindings.FPDFPage_GetAnnot(self.handle, index);
+
+                (annotation, bindings.FPDFAnnot_GetFlags(annotation))
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Ok(Vec::with_capacity(indices.len()));
+
+        for index in indices.iter() {
+            if *index >= annotations.len() || annotations[*index].0.is_null() {
+                continue;
+            }
+
+            // Hide every annotation other than the one being rendered.
+
+            for (other_index, (annotation, flags)) in annotations.iter().enumerate() {
+                if !annotation.is_null() {
+                    bindings.FPDFAnnot_SetFlags(
+                        *annotation,
+                        if other_index == *index {
+                            *flags
+                        } else {
+                            *flags | FPDF_ANNOT_FLAG_HIDDEN as c_int
+                        },
+                    );
+                }
+            }
+
+            match Self::render_annotation_overlay(
+                self.handle,
+                *index,
+                annotations[*index].0,
+                &settings,
+                bindings,
+            ) {
+                Ok(Some(overlay)) => {
+                    if let Ok(overlays) = result.as_mut() {
+                        overlays.push(overlay);
+                    }
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    result = Err(error);
+
+                    break;
+                }
+            }
+        }
+
+        // Restore the original flags of every annotation, and the removed page content.
+
+        for (annotation, flags) in annotations.iter() {
+            if !annotation.is_null() {
+                bindings.FPDFAnnot_SetFlags(*annotation, *flags);
+                bindings.FPDFPage_CloseAnnot(*annotation);
+            }
+        }
+
+        for object in removed_objects {
+            bindings.FPDFPage_InsertObject(self.handle, object);
+        }
+
+        result
+    }
+
+    /// Renders the given annotation onto a transparent bitmap sized to the annotation's bounds,
+    /// returning `None` if the annotation has no bounds.
+    fn render_annotation_overlay(
+        page: FPDF_PAGE,
+        index: PdfPageAnnotationIndex,
+        annotation: FPDF_ANNOTATION,
+        settings: &PdfRenderSettings,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Option<PdfPageAnnotationOverlay<'a>>, PdfiumError> {
+        let mut rect = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: 0.0,
+            bottom: 0.0,
+        };
+
+        if !bindings.is_true(bindings.FPDFAnnot_GetRect(annotation, &mut rect)) {
+            return Ok(None);
+        }
+
+        let page_to_device = |x: f32, y: f32| {
+            let mut device_x: c_int = 0;
+
+            let mut device_y: c_int = 0;
+
+            bindings
+                .is_true(bindings.FPDF_PageToDevice(
+                    page,
+                    0,
+                    0,
+                    settings.width,
+                    settings.height,
+                    settings.rotate,
+                    x as c_double,
+                    y as c_double,
+                    &mut device_x,
+                    &mut device_y,
+                ))
+                .then_some((device_x, device_y))
+        };
+
+        let (x1, y1) = match page_to_device(rect.left, rect.top) {
+            Some(point) => point,
+            None => return Ok(None),
+        };
+
+        let (x2, y2) = match page_to_device(rect.right, rect.bottom) {
+            Some(point) => point,
+            None => return Ok(None),
+        };
+
+        // Page rotation may swap or invert the corners of the rectangle.
+
+        let left = x1.min(x2);
+
+        let top = y1.min(y2);
+
+        let width = (x2 - x1).abs().clamp(1, Pixels::MAX as i32) as Pixels;
+
+        let height = (y2 - y1).abs().clamp(1, Pixels::MAX as i32) as Pixels;
+
+        let bitmap = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, bindings)?;
+
+        let bitmap_handle = *bitmap.handle();
+
+        bindings.FPDFBitmap_FillRect(bitmap_handle, 0, 0, width as c_int, height as c_int, 0);
+
+        // Offsetting the render origin by the overlay's position renders just the portion
+        // of the page covered by the annotation into the overlay bitmap.
+
+        bindings.FPDF_RenderPageBitmap(
+            bitmap_handle,
+            page,
+            -left,
+            -top,
+            settings.width,
+            settings.height,
+            settings.rotate,
+            settings.render_flags | FPDF_ANNOT as c_int,
+        );
+
+        if let Some(error) = bindings.get_pdfium_last_error() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(error));
+        }
+
+        Ok(Some(PdfPageAnnotationOverlay::new(
+            index, bitmap, left, top,
+        )))
+    }
+
+    /// Renders this [PdfPage] into the given [PdfBitmap] using the given the given pixel dimensions
+    /// and page rotation.
+    ///
+    /// It is the responsibility of the caller to ensure the given pixel width and height
+    /// correctly maintain the page's aspect ratio. The size of the buffer backing the given bitmap
+    /// must be sufficiently large to hold the rendered image or an error will be returned.
+    ///
+    /// See also [PdfPage::render_into_bitmap_with_config()], which calculates the correct pixel dimensions,
+    /// rotation settings, and rendering options to apply from a [PdfRenderConfig] object.
+    pub fn render_into_bitmap(
+        &self,
+        bitmap: &mut PdfBitmap,
+        width: u16,
+        height: u16,
+        rotation: Option<PdfBitmapRotation>,
+    ) -> Result<(), PdfiumError> {
+        let mut config = PdfRenderConfig::new()
+            .set_target_width(width)
+            .set_target_height(height);
+
+        if let Some(rotation) = rotation {
+            config = config.rotate(rotation, true);
+        }
+
+        self.render_into_bitmap_with_config(bitmap, &config)
+    }
+
+    /// Renders this [PdfPage] into the given [PdfBitmap] using pixel dimensions, page rotation settings,
+    /// and rendering options configured in the given [PdfRenderConfig].
+    ///
+    /// The size of the buffer backing the given bitmap must be sufficiently large to hold the
+    /// rendered image or an error will be returned.
+    #[inline]
+    pub fn render_into_bitmap_with_config(
+        &self,
+        bitmap: &mut PdfBitmap,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        self.render_into_bitmap_with_settings(bitmap, config.apply_to_page(self))
+    }
+
+    /// Renders only the interactive form fields on this [PdfPage], if any, into the given
+    /// [PdfBitmap] using pixel dimensions, page rotation settings, and rendering options
+    /// configured in the given [PdfRenderConfig]. The form fields are drawn on top of any
+    /// existing content in the bitmap; the bitmap is never cleared, irrespective of the
+    /// setting of [PdfRenderConfig::clear_before_rendering()].
+    ///
+    /// This allows the static content of a page to be rendered once, and only the form field
+    /// layer to be re-rendered when a form field value changes. For the form fields to align
+    /// exactly with the base rendering, the bitmap should have been rendered using the same
+    /// [PdfRenderConfig] with form data rendering enabled. Custom transformation matrices and
+    /// clipping rectangles cannot be applied to the rendering of form fields; they are ignored.
+    ///
+    /// If the document containing this page has no form, this function does nothing.
+    pub fn render_form_fields_onto(
+        &self,
+        bitmap: &mut PdfBitmap,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        self.render_form_data_into_bitmap_with_settings(bitmap, &config.apply_to_page(self))
+    }
+
+    /// Renders this [PdfPage] into the given [PdfBitmap] using the given [PdfRenderSettings].
+    /// The size of the buffer backing the given bitmap must be sufficiently large to hold
+    /// the rendered image or an error will be returned.
+    pub(crate) fn render_into_bitmap_with_settings(
+        &self,
+        bitmap: &mut PdfBitmap,
+        settings: PdfRenderSettings,
+    ) -> Result<(), PdfiumError> {
+        match settings.visible_layers.as_ref() {
+            Some(layers) => {
+                // Pdfium does not provide a way to render a page with a custom set of visible
+                // layers, so we temporarily hide the page objects in all other layers, then
+                // restore them once rendering is complete.
+
+                let mut hidden_objects = Vec::new();
+
+                let result = self
+                    .hide_objects_outside_layers(
+                        &(0..self.bindings().FPDFPage_CountObjects(self.handle))
+                            .map(|index| self.bindings().FPDFPage_GetObject(self.handle, index))
+                            .collect::<Vec<_>>(),
+                        layers,
+                        &mut hidden_objects,
+                    )
+                    .and_then(|_| {
+                        self.render_visible_objects_into_bitmap_with_settings(bitmap, &settings)
+                    });
+
+                for (object, matrix) in hidden_objects.iter().rev() {
+                    self.bindings().FPDFPageObj_SetMatrix(*object, matrix);
+                }
+
+                result
+            }
+            None => self.render_visible_objects_into_bitmap_with_settings(bitmap, &settings),
+        }
+    }
+
+    /// Renders the currently visible page objects on this [PdfPage] into the given [PdfBitmap]
+    /// using the given [PdfRenderSettings]. Used by [PdfPage::render_into_bitmap_with_settings()].
+    fn render_visible_objects_into_bitmap_with_settings(
+        &self,
+        bitmap: &mut PdfBitmap,
+        settings: &PdfRenderSettings,
+    ) -> Result<(), PdfiumError> {
+        let bitmap_handle = *bitmap.handle();
+
+        self.clear_bitmap_with_settings(bitmap, settings)?;
+
+        if settings.do_render_form_data || settings.color_scheme.is_some() {
+            // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
+            // (Custom transforms cannot be applied to the rendering of form fields, nor
+            // combined with a color scheme.)
+
+            if let Some(color_scheme) = settings.color_scheme.as_ref() {
+                // Pdfium only supports color schemes through its progressive rendering API,
+                // which requires a pause callback. A callback that never asks Pdfium to pause
+                // ensures rendering completes in a single call.
+
+                let mut never_pause = || false;
+
+                let mut pause = get_pdfium_pause_from_callback(&mut never_pause);
+
+                let status = self.bindings().FPDF_RenderPageBitmapWithColorScheme_Start(
+                    bitmap_handle,
+                    self.handle,
+                    0,
+                    0,
+                    settings.width,
+                    settings.height,
+                    settings.rotate,
+                    settings.render_flags,
+                    color_scheme,
+                    pause.as_ifsdk_pause_mut_ptr(),
+                );
+
+                self.bindings().FPDF_RenderPage_Close(self.handle);
+
+                if status != FPDF_RENDER_DONE as c_int {
+                    return Err(PdfiumError::PdfiumLibraryInternalError(
+                        self.bindings()
+                            .get_pdfium_last_error()
+                            .unwrap_or(PdfiumInternalError::Unknown),
+                    ));
+                }
+            } else {
+                self.bindings().FPDF_RenderPageBitmap(
+                    bitmap_handle,
+                    self.handle,
+                    0,
+                    0,
+                    settings.width,
+                    settings.height,
+                    settings.rotate,
+                    settings.render_flags,
+                );
+            }
+
+            if let Some(error) = self.bindings().get_pdfium_last_error() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(error));
+            }
+
+            if settings.do_render_form_data {
+                // Render user-supplied form data, if any, as an overlay on top of the page.
+
+                self.render_form_data_into_bitmap_with_settings(bitmap, settings)?;
+            }
+        } else {
+            // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
+
+            self.bindings().FPDF_RenderPageBitmapWithMatrix(
+                bitmap_handle,
+                self.handle,
+                &settings.matrix,
+                &settings.clipping,
+                settings.render_flags,
+            );
+
+            if let Some(error) = self.bindings().get_pdfium_last_error() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(error));
+            }
+        }
+
+        if settings.do_premultiply_alpha {
+            bitmap.premultiply_alpha()?;
+        }
+
+        Ok(())
+    }
+
+    /// Hides every page object in the given page objects that belongs to an optional content
+    /// group not included in the given list of layer names, recursing into form XObjects.
+    /// Each hidden object is collapsed to a single point far outside the page by temporarily
+    /// replacing its transformation matrix; the hidden objects and their original matrices
+    /// are appended to the given vector so they can be restored after rendering.
+    fn hide_objects_outside_layers(
+        &self,
+        objects: &[FPDF_PAGEOBJECT],
+        layers: &[String],
+        hidden_objects: &mut Vec<(FPDF_PAGEOBJECT, FS_MATRIX)>,
+    ) -> Result<(), PdfiumError> {
+        let bindings = self.bindings();
+
+        for handle in objects.iter().copied() {
+            if handle.is_null() {
+                continue;
+            }
+
+            let object = PdfPageObject::from_pdfium(handle, Some(self.handle), None, bindings);
+
+            let is_hidden = object
+                .optional_content_group()
+                .map(|layer| !layers.contains(&layer))
+                .unwrap_or(false);
+
+            if is_hidden {
+                let mut matrix = FS_MATRIX {
+                    a: 0.0,
+                    b: 0.0,
+                    c: 0.0,
+                    d: 0.0,
+                    e: 0.0,
+                    f: 0.0,
+                };
+
+                if !bindings.is_true(bindings.FPDFPageObj_GetMatrix(handle, &mut matrix)) {
+                    return Err(PdfiumError::PageObjectNotHideable);
+                }
+
+                let hidden_matrix = FS_MATRIX {
+                    a: 0.0,
+                    b: 0.0,
+                    c: 0.0,
+                    d: 0.0,
+                    e: -1_000_000.0,
+                    f: -1_000_000.0,
+                };
+
+                // Pdfium cannot set the matrix of a shading object.
+
+                if !bindings.is_true(bindings.FPDFPageObj_SetMatrix(handle, &hidden_matrix)) {
+                    return Err(PdfiumError::PageObjectNotHideable);
+                }
+
+                hidden_objects.push((handle, matrix));
+            } else if object.object_type() == PdfPageObjectType::FormFragment {
+                self.hide_objects_outside_layers(
+                    &(0..bindings.FPDFFormObj_CountObjects(handle).max(0) as c_ulong)
+                        .map(|index| bindings.FPDFFormObj_GetObject(handle, index))
+                        .collect::<Vec<_>>(),
+                    layers,
+                    hidden_objects,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clears the given [PdfBitmap] by setting every pixel to the clear color in the given
+    /// [PdfRenderSettings], if clearing is enabled.
+    fn clear_bitmap_with_settings(
+        &self,
+        bitmap: &PdfBitmap,
+        settings: &PdfRenderSettings,
+    ) -> Result<(), PdfiumError> {
+        if settings.do_clear_bitmap_before_rendering {
+            // Clear the bitmap buffer by setting every pixel to a known color.
+
+            self.bindings().FPDFBitmap_FillRect(
+                *bitmap.handle(),
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.clear_color,
+            );
+
+            if let Some(error) = self.bindings().get_pdfium_last_error() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders only the user-supplied form data for this [PdfPage], if any, into the given
+    /// [PdfBitmap] using the given [PdfRenderSettings]. Form data is drawn as an overlay
+    /// on top of any existing content in the bitmap.
+    fn render_form_data_into_bitmap_with_settings(
+        &self,
+        bitmap: &mut PdfBitmap,
+        settings: &PdfRenderSettings,
+    ) -> Result<(), PdfiumError> {
+        if let Some(form) = self.document().form() {
+            if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
+                for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
+                    self.bindings().FPDF_SetFormFieldHighlightColor(
+                        *form.handle(),
+                        *form_field_type,
+                        *color,
+                    );
+
+                    self.bindings()
+                        .FPDF_SetFormFieldHighlightAlpha(*form.handle(), *alpha);
+                }
+            }
+
+            if let Some(alpha) = settings.form_field_highlight_alpha {
+                // Pdfium applies a single highlight alpha value to all form field types,
+                // so this setting overrides the alpha values of any highlight colors above.
+
+                self.bindings()
+                    .FPDF_SetFormFieldHighlightAlpha(*form.handle(), alpha);
+            }
+
+            self.bindings().FPDF_FFLDraw(
+                *form.handle(),
+                *bitmap.handle(),
+                self.handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+            );
+
+            if let Some(error) = self.bindings().get_pdfium_last_error() {
+                return Err(PdfiumError::PdfiumLibraryInternalError(error));
             }
         }
 
@@ -929,11 +2125,35 @@ impl<'a> PdfPage<'a> {
     }
 
     /// Flattens all annotations and form fields on this [PdfPage] into the page contents.
+    ///
+    /// If the containing `PdfDocument` has a form, any form field widgets lacking a valid
+    /// appearance stream will have their appearance regenerated from the current field value
+    /// before flattening, so that the flattened output matches the interactive display.
     pub fn flatten(&mut self) -> Result<(), PdfiumError> {
         // TODO: AJRC - 28/5/22 - consider allowing the caller to set the FLAT_NORMALDISPLAY or FLAT_PRINT flag.
         let flag = FLAT_PRINT;
 
-        match self.bindings().FPDFPage_Flatten(self.handle, flag as c_int) as u32 {
+        // Pdfium flattens each widget using its stored appearance stream. Documents that set
+        // NeedAppearances, or that simply omit appearance streams, may lack the "on" appearance
+        // for checked checkboxes and selected radio buttons, which would then be dropped from
+        // the flattened output. Attaching the page to the form fill environment causes Pdfium
+        // to regenerate any missing widget appearances from the current field values.
+
+        let form_handle = self.document().form().map(|form| *form.handle());
+
+        if let Some(form_handle) = form_handle {
+            self.bindings()
+                .FORM_OnAfterLoadPage(self.handle, form_handle);
+        }
+
+        let result = self.bindings().FPDFPage_Flatten(self.handle, flag as c_int);
+
+        if let Some(form_handle) = form_handle {
+            self.bindings()
+                .FORM_OnBeforeClosePage(self.handle, form_handle);
+        }
+
+        match result as u32 {
             FLATTEN_SUCCESS => {
                 self.is_content_regeneration_required = true;
 
@@ -945,6 +2165,148 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Creates a new single-page [PdfDocument] containing only the given region of this [PdfPage].
+    ///
+    /// This page is copied into the new document, and every page object lying entirely outside
+    /// the given rectangle is removed from the copy. The remaining page content is then clipped
+    /// to the rectangle and translated so the bottom left corner of the rectangle lies at the
+    /// origin of the new page, which is sized to the rectangle. Text and vector graphics remain
+    /// vector content, so this is a lossless way of extracting a single figure or table.
+    ///
+    /// Page objects that lie partly inside the rectangle are retained in full; the portions
+    /// outside the rectangle are hidden by the clip, but any text they contain can still be
+    /// extracted from the new document.
+    ///
+    /// The rectangle is measured in the coordinate space of this page, ignoring any page
+    /// rotation. Only the page content is exported; annotations and form fields are not
+    /// included. Changes to this page that have not yet been committed by regenerating
+    /// the page's content will not be included in the new document.
+    pub fn export_region_as_pdf(&self, rect: PdfRect) -> Result<PdfDocument<'a>, PdfiumError> {
+        if rect.width().value <= 0.0 || rect.height().value <= 0.0 {
+            return Err(PdfiumError::RegionHasNoArea);
+        }
+
+        let page_index =
+            PdfPageIndexCache::get_index_for_page(*self.document.handle(), self.handle)
+                .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+        let bindings = self.bindings();
+
+        let handle = bindings.FPDF_CreateNewDocument();
+
+        if handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        // Wrapping the new document immediately ensures it is closed if any of the
+        // following steps fail.
+
+        let mut document = PdfDocument::from_pdfium(handle, bindings);
+
+        document.set_version(PdfDocumentVersion::DEFAULT_VERSION);
+
+        PdfPages::copy_page_range_between_documents(
+            *self.document.handle(),
+            page_index..=page_index,
+            handle,
+            0,
+            bindings,
+        )?;
+
+        let page = bindings.FPDF_LoadPage(handle, 0);
+
+        if page.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        for index in (0..bindings.FPDFPage_GetAnnotCount(page)).rev() {
+            bindings.FPDFPage_RemoveAnnot(page, index);
+        }
+
+        // Remove every page object lying entirely outside the rectangle, so that neither
+        // its content nor any text it contains is carried over into the new document.
+
+        for index in (0..bindings.FPDFPage_CountObjects(page)).rev() {
+            let object = bindings.FPDFPage_GetObject(page, index);
+
+            let mut left = 0.0;
+
+            let mut bottom = 0.0;
+
+            let mut right = 0.0;
+
+            let mut top = 0.0;
+
+            if bindings.is_true(bindings.FPDFPageObj_GetBounds(
+                object,
+                &mut left,
+                &mut bottom,
+                &mut right,
+                &mut top,
+            )) && !PdfRect::new_from_values(bottom, left, top, right).does_overlap(&rect)
+                && bindings.is_true(bindings.FPDFPage_RemoveObject(page, object))
+            {
+                bindings.FPDFPageObj_Destroy(object);
+            }
+        }
+
+        let is_content_generated = bindings.is_true(bindings.FPDFPage_GenerateContent(page));
+
+        // Wrap the regenerated content in a clipping path around the rectangle, and translate
+        // the clipped content to the origin of the new page. The clipping rectangle is given
+        // in the coordinate space of the untranslated content.
+
+        let is_content_clipped = is_content_generated
+            && bindings.is_true(bindings.FPDFPage_TransFormWithClip(
+                page,
+                &FS_MATRIX {
+                    a: 1.0,
+                    b: 0.0,
+                    c: 0.0,
+                    d: 1.0,
+                    e: -rect.left.value,
+                    f: -rect.bottom.value,
+                },
+                &FS_RECTF {
+                    left: rect.left.value,
+                    top: rect.top.value,
+                    right: rect.right.value,
+                    bottom: rect.bottom.value,
+                },
+            ));
+
+        if is_content_clipped {
+            let (width, height) = (rect.width().value, rect.height().value);
+
+            bindings.FPDFPage_SetRotation(page, 0);
+            bindings.FPDFPage_SetMediaBox(page, 0.0, 0.0, width, height);
+            bindings.FPDFPage_SetCropBox(page, 0.0, 0.0, width, height);
+            bindings.FPDFPage_SetBleedBox(page, 0.0, 0.0, width, height);
+            bindings.FPDFPage_SetTrimBox(page, 0.0, 0.0, width, height);
+            bindings.FPDFPage_SetArtBox(page, 0.0, 0.0, width, height);
+        }
+
+        bindings.FPDF_ClosePage(page);
+
+        if is_content_clipped {
+            Ok(document)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
     /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
     pub fn delete(self) -> Result<(), PdfiumError> {
         let index = PdfPageIndexCache::get_index_for_page(*self.document.handle(), self.handle)
@@ -956,6 +2318,8 @@ impl<'a> PdfPage<'a> {
         if let Some(error) = self.bindings().get_pdfium_last_error() {
             Err(PdfiumError::PdfiumLibraryInternalError(error))
         } else {
+            self.document().invalidate_page_cache();
+
             PdfPageIndexCache::delete_pages_at_index(*self.document.handle(), index, 1);
 
             Ok(())
@@ -1066,17 +2430,54 @@ impl<'a> Drop for PdfPage<'a> {
             debug_assert!(result.is_ok());
         }
 
-        self.bindings().FPDF_ClosePage(self.handle);
+        if let Some(cached_page) = self.cached_page.take() {
+            // Cached pages are closed only once they have been evicted from the page cache
+            // and are no longer in use.
+
+            PdfCachedPage::release(cached_page, *self.document.handle(), self.bindings());
+        } else {
+            self.bindings().FPDF_ClosePage(self.handle);
 
-        PdfPageIndexCache::remove_index_for_page(*self.document.handle(), self.handle);
+            PdfPageIndexCache::remove_index_for_page(*self.document.handle(), self.handle);
+        }
     }
 }
 
+/// Continues the given 64-bit FNV-1a hash over the given bytes. Unlike the hashers provided
+/// by the standard library, FNV-1a is fully specified, so the result is stable across
+/// Rust versions and platforms.
+#[inline]
+fn fingerprint_hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 #[cfg(test)]
 mod test {
+    use super::SEARCH_HIGHLIGHT_KEY;
+    use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
     use crate::prelude::*;
     use crate::utils::test::test_bind_to_pdfium;
 
+    #[test]
+    fn test_fingerprint_hash() {
+        // Published FNV-1a 64-bit test vectors.
+
+        assert_eq!(
+            super::fingerprint_hash(super::FINGERPRINT_HASH_SEED, b""),
+            0xcbf2_9ce4_8422_2325
+        );
+        assert_eq!(
+            super::fingerprint_hash(super::FINGERPRINT_HASH_SEED, b"a"),
+            0xaf63_dc4c_8601_ec8c
+        );
+        assert_eq!(
+            super::fingerprint_hash(super::FINGERPRINT_HASH_SEED, b"foobar"),
+            0x8594_4171_f739_67e8
+        );
+    }
+
     #[test]
     fn test_pdf_rect_is_inside() {
         assert!(PdfRect::new_from_values(3.0, 3.0, 9.0, 9.0)
@@ -1137,4 +2538,357 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_export_region_as_pdf() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let region = document
+            .pages()
+            .first()?
+            .export_region_as_pdf(PdfRect::new_from_values(100.0, 50.0, 400.0, 250.0))?;
+
+        let bytes = region.save_to_bytes()?;
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(reloaded.pages().len(), 1);
+
+        let page = reloaded.pages().first()?;
+
+        assert_eq!(page.width().value, 200.0);
+        assert_eq!(page.height().value, 300.0);
+        assert!(!page.objects().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_region_as_pdf_excludes_text_outside_region() -> Result<(), PdfiumError> {
+        // Places one text object inside the exported region and another outside it, then
+        // confirms that only the text inside the region can be extracted from the new page.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = PdfFont::helvetica(&document);
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "Inside",
+            &font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Outside",
+            &font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let region =
+            page.export_region_as_pdf(PdfRect::new_from_values(50.0, 50.0, 300.0, 300.0))?;
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(region.save_to_bytes()?, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let text = page.text()?;
+
+        assert_eq!(page.objects().len(), 1);
+        assert!(text.all().contains("Inside"));
+        assert!(!text.all().contains("Outside"));
+        assert!(!text
+            .inside_rect(PdfRect::new_from_values(
+                0.0,
+                0.0,
+                page.height().value,
+                page.width().value
+            ))
+            .contains("Outside"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_rotation_persists_after_save() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        document
+            .pages()
+            .first()?
+            .set_rotation(PdfBitmapRotation::Degrees270);
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(
+            reloaded.pages().first()?.rotation()?,
+            PdfBitmapRotation::Degrees270
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_color_scheme() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_width(500)
+            .set_clear_color(PdfColor::SOLID_WHITE)
+            .set_color_scheme(
+                PdfColor::SOLID_RED,
+                PdfColor::SOLID_RED,
+                PdfColor::SOLID_BLUE,
+                PdfColor::SOLID_BLUE,
+            );
+
+        let page = document.pages().first()?;
+
+        let bitmap = page.render_with_config(&config)?;
+
+        // A failed render would leave every pixel set to the clear color.
+
+        assert!(bitmap.as_bytes().iter().any(|byte| *byte != 255));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_visible_layers() -> Result<(), PdfiumError> {
+        // Places a filled rectangle in an optional content group, then confirms that the
+        // rectangle is rendered only when its layer is selected, and that hiding it during
+        // rendering leaves the rectangle unchanged.
+
+        use crate::page_object_private::internal::PdfPageObjectPrivate;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let bindings = pdfium.bindings();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 400.0, 400.0),
+            None,
+            None,
+            Some(PdfColor::SOLID_RED),
+        )?;
+
+        let handle = *object.get_object_handle();
+
+        let mark = bindings.FPDFPageObj_AddMark(handle, "OC");
+
+        assert!(bindings.is_true(bindings.FPDFPageObjMark_SetStringParam(
+            *document.handle(),
+            handle,
+            mark,
+            "Name",
+            "Shapes",
+        )));
+
+        assert_eq!(object.optional_content_group(), Some("Shapes".to_string()));
+
+        let matrix = object.matrix()?;
+
+        let is_drawn = |config: PdfRenderConfig| -> Result<bool, PdfiumError> {
+            let config = config
+                .set_target_width(500)
+                .set_clear_color(PdfColor::SOLID_WHITE);
+
+            Ok(page
+                .render_with_config(&config)?
+                .as_bytes()
+                .iter()
+                .any(|byte| *byte != 255))
+        };
+
+        assert!(is_drawn(
+            PdfRenderConfig::new().set_visible_layers(&["Shapes"])
+        )?);
+        assert!(!is_drawn(
+            PdfRenderConfig::new().set_visible_layers(&["Other"])
+        )?);
+
+        // The layer filter must also apply when rendering form data.
+
+        assert!(!is_drawn(
+            PdfRenderConfig::new()
+                .set_visible_layers(&["Other"])
+                .render_form_data(true)
+        )?);
+
+        assert_eq!(object.matrix()?, matrix);
+        assert!(is_drawn(PdfRenderConfig::new())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_annotation_overlays() -> Result<(), PdfiumError> {
+        // Renders overlays for the annotations on the first page of the given test PDF file,
+        // confirming that the page's content and annotations are left unchanged, and that
+        // configurations with a custom transformation matrix are rejected.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotations-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation_type = page
+            .annotations()
+            .iter()
+            .next()
+            .map(|annotation| annotation.annotation_type())
+            .unwrap();
+
+        let object_count = page.objects().len();
+
+        let flags = page
+            .annotations()
+            .iter()
+            .map(|annotation| annotation.flags())
+            .collect::<Vec<_>>();
+
+        let config = PdfRenderConfig::new().set_target_width(1000);
+
+        let overlays = page.render_annotation_overlays(annotation_type, &config)?;
+
+        assert!(!overlays.is_empty());
+        assert_eq!(page.objects().len(), object_count);
+        assert_eq!(
+            page.annotations()
+                .iter()
+                .map(|annotation| annotation.flags())
+                .collect::<Vec<_>>(),
+            flags
+        );
+
+        assert!(matches!(
+            page.render_annotation_overlays(
+                annotation_type,
+                &config.rotate_clockwise_degrees(10.0)?
+            ),
+            Err(PdfiumError::AnnotationOverlayTransformNotSupported)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_crop_rejects_negative_margin() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::SOLID_RED),
+        )?;
+
+        assert!(matches!(
+            page.auto_crop(PdfPoints::new(-1.0)),
+            Err(PdfiumError::NegativeMargin)
+        ));
+
+        page.auto_crop(PdfPoints::new(10.0))?;
+
+        let crop = page.boundaries().crop()?.bounds;
+
+        assert_eq!(
+            (
+                crop.left.value,
+                crop.bottom.value,
+                crop.right.value,
+                crop.top.value
+            ),
+            (90.0, 90.0, 210.0, 210.0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight_search_results() -> Result<(), PdfiumError> {
+        // Highlights two matches on a page that already has an unrelated annotation, then
+        // confirms that repeating the search does not accumulate highlights, and that clearing
+        // the highlights removes only the tagged annotations.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = PdfFont::helvetica(&document);
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "apple banana apple",
+            &font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.annotations_mut().create_ink_annotation()?;
+
+        let count_highlights = |page: &PdfPage| {
+            page.annotations()
+                .iter()
+                .filter(|annotation| {
+                    page.bindings().is_true(
+                        page.bindings()
+                            .FPDFAnnot_HasKey(*annotation.handle(), SEARCH_HIGHLIGHT_KEY),
+                    )
+                })
+                .count()
+        };
+
+        assert_eq!(
+            page.highlight_search_results("apple", PdfColor::SOLID_YELLOW)?,
+            2
+        );
+        assert_eq!(count_highlights(&page), 2);
+        assert_eq!(page.annotations().len(), 3);
+
+        assert_eq!(
+            page.highlight_search_results("apple", PdfColor::SOLID_YELLOW)?,
+            2
+        );
+        assert_eq!(count_highlights(&page), 2);
+        assert_eq!(page.annotations().len(), 3);
+
+        assert_eq!(page.clear_search_result_highlights()?, 2);
+        assert_eq!(count_highlights(&page), 0);
+        assert_eq!(page.annotations().len(), 1);
+
+        Ok(())
+    }
 }
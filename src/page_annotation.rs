@@ -1,19 +1,25 @@
 //! Defines the [PdfPageAnnotation] struct, exposing functionality related to a single annotation.
 
 use crate::bindgen::{
-    FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_CARET, FPDF_ANNOT_CIRCLE,
-    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
-    FPDF_ANNOT_LINE, FPDF_ANNOT_LINK, FPDF_ANNOT_MOVIE, FPDF_ANNOT_POLYGON, FPDF_ANNOT_POLYLINE,
-    FPDF_ANNOT_POPUP, FPDF_ANNOT_PRINTERMARK, FPDF_ANNOT_REDACT, FPDF_ANNOT_RICHMEDIA,
-    FPDF_ANNOT_SCREEN, FPDF_ANNOT_SOUND, FPDF_ANNOT_SQUARE, FPDF_ANNOT_SQUIGGLY, FPDF_ANNOT_STAMP,
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION,
+    FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_CARET, FPDF_ANNOT_CIRCLE, FPDF_ANNOT_FILEATTACHMENT,
+    FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE, FPDF_ANNOT_FLAG_LOCKED,
+    FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW, FPDF_ANNOT_FLAG_NOZOOM,
+    FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY, FPDF_ANNOT_FLAG_TOGGLENOVIEW,
+    FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK, FPDF_ANNOT_LINE, FPDF_ANNOT_LINK,
+    FPDF_ANNOT_MOVIE, FPDF_ANNOT_POLYGON, FPDF_ANNOT_POLYLINE, FPDF_ANNOT_POPUP,
+    FPDF_ANNOT_PRINTERMARK, FPDF_ANNOT_REDACT, FPDF_ANNOT_RICHMEDIA, FPDF_ANNOT_SCREEN,
+    FPDF_ANNOT_SOUND, FPDF_ANNOT_SQUARE, FPDF_ANNOT_SQUIGGLY, FPDF_ANNOT_STAMP,
     FPDF_ANNOT_STRIKEOUT, FPDF_ANNOT_TEXT, FPDF_ANNOT_THREED, FPDF_ANNOT_TRAPNET,
     FPDF_ANNOT_UNDERLINE, FPDF_ANNOT_UNKNOWN, FPDF_ANNOT_WATERMARK, FPDF_ANNOT_WIDGET,
     FPDF_ANNOT_XFAWIDGET, FPDF_PAGE,
 };
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
 use crate::document::PdfDocument;
-use crate::error::PdfiumError;
-use crate::page::PdfRect;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page::{PdfPoints, PdfRect};
 use crate::page_annotation_circle::PdfPageCircleAnnotation;
 use crate::page_annotation_free_text::PdfPageFreeTextAnnotation;
 use crate::page_annotation_highlight::PdfPageHighlightAnnotation;
@@ -29,6 +35,8 @@ use crate::page_annotation_strikeout::PdfPageStrikeoutAnnotation;
 use crate::page_annotation_text::PdfPageTextAnnotation;
 use crate::page_annotation_underline::PdfPageUnderlineAnnotation;
 use crate::page_annotation_unsupported::PdfPageUnsupportedAnnotation;
+use bitflags::bitflags;
+use std::os::raw::c_int;
 
 /// The type of a single [PdfPageAnnotation], as defined in table 8.20 of the PDF Reference,
 /// version 1.7, on page 615.
@@ -159,6 +167,41 @@ impl PdfPageAnnotationType {
     }
 }
 
+bitflags! {
+    /// The flags set on a single [PdfPageAnnotation], as defined in table 8.16 of the
+    /// PDF Reference, version 1.7, on page 608.
+    pub struct PdfPageAnnotationFlags: u32 {
+        /// If the annotation type is not supported, do not display the annotation.
+        const INVISIBLE = FPDF_ANNOT_FLAG_INVISIBLE;
+
+        /// Do not display or print the annotation, or allow the user to interact with it.
+        const HIDDEN = FPDF_ANNOT_FLAG_HIDDEN;
+
+        /// Print the annotation when the page is printed.
+        const PRINT = FPDF_ANNOT_FLAG_PRINT;
+
+        /// Do not scale the annotation's appearance to match the magnification of the page.
+        const NO_ZOOM = FPDF_ANNOT_FLAG_NOZOOM;
+
+        /// Do not rotate the annotation's appearance to match the rotation of the page.
+        const NO_ROTATE = FPDF_ANNOT_FLAG_NOROTATE;
+
+        /// Do not display the annotation on screen, or allow the user to interact with it,
+        /// but still print it if the [PdfPageAnnotationFlags::PRINT] flag is set.
+        const NO_VIEW = FPDF_ANNOT_FLAG_NOVIEW;
+
+        /// Do not allow the user to interact with the annotation.
+        const READ_ONLY = FPDF_ANNOT_FLAG_READONLY;
+
+        /// Do not allow the user to delete the annotation or modify its properties.
+        const LOCKED = FPDF_ANNOT_FLAG_LOCKED;
+
+        /// Invert the interpretation of the [PdfPageAnnotationFlags::NO_VIEW] flag
+        /// for certain events.
+        const TOGGLE_NO_VIEW = FPDF_ANNOT_FLAG_TOGGLENOVIEW;
+    }
+}
+
 /// A single user annotation on a `PdfPage`.
 pub enum PdfPageAnnotation<'a> {
     Circle(PdfPageCircleAnnotation<'a>),
@@ -387,6 +430,17 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns a mutable reference to the underlying [PdfPageFreeTextAnnotation] for this
+    /// [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::FreeText].
+    #[inline]
+    pub fn as_free_text_annotation_mut(&mut self) -> Option<&mut PdfPageFreeTextAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::FreeText(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns the underlying [PdfPageHighlightAnnotation] for this [PdfPageAnnotation],
     /// if this annotation has an annotation type of [PdfPageAnnotationType::Highlight].
     #[inline]
@@ -407,6 +461,17 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns a mutable reference to the underlying [PdfPageInkAnnotation] for this
+    /// [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Ink].
+    #[inline]
+    pub fn as_ink_annotation_mut(&mut self) -> Option<&mut PdfPageInkAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Ink(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns the underlying [PdfPageLinkAnnotation] for this [PdfPageAnnotation],
     /// if this annotation has an annotation type of [PdfPageAnnotationType::Link].
     #[inline]
@@ -497,6 +562,78 @@ pub trait PdfPageAnnotationCommon {
     /// Returns the bounding box of this [PdfPageAnnotation].
     fn bounds(&self) -> Result<PdfRect, PdfiumError>;
 
+    /// Sets the bounding box of this [PdfPageAnnotation], moving or resizing the annotation
+    /// on its containing page.
+    fn set_bounds(&mut self, bounds: PdfRect) -> Result<(), PdfiumError>;
+
+    /// Returns the border of this [PdfPageAnnotation] as a tuple of its horizontal corner radius,
+    /// vertical corner radius, and border width.
+    fn border(&self) -> Result<(PdfPoints, PdfPoints, PdfPoints), PdfiumError>;
+
+    /// Sets the border of this [PdfPageAnnotation] to the given horizontal corner radius,
+    /// vertical corner radius, and border width. A border width of zero disables the border.
+    ///
+    /// Pdfium does not regenerate the appearance stream of the annotation when its border
+    /// changes, so the new border may not be visible when rendering an annotation that
+    /// already has an appearance stream.
+    fn set_border(
+        &mut self,
+        horizontal_radius: PdfPoints,
+        vertical_radius: PdfPoints,
+        width: PdfPoints,
+    ) -> Result<(), PdfiumError>;
+
+    /// Returns the color of this [PdfPageAnnotation], or `None` if no color is set. Depending on
+    /// the annotation type, this is the color of the annotation's border, its title bar,
+    /// its text markup, or the annotation's icon. The alpha channel of the returned color
+    /// reflects the opacity of the annotation.
+    ///
+    /// Pdfium cannot report the color of an annotation that has an appearance stream,
+    /// since the appearance stream's own colors take priority; an error will be returned
+    /// in this case.
+    fn color(&self) -> Result<Option<PdfColor>, PdfiumError>;
+
+    /// Sets the color of this [PdfPageAnnotation]. The alpha channel of the given color
+    /// sets the opacity of the annotation.
+    ///
+    /// Pdfium cannot set the color of an annotation that has an appearance stream;
+    /// an error will be returned in this case.
+    fn set_color(&mut self, color: PdfColor) -> Result<(), PdfiumError>;
+
+    /// Returns the interior color used to fill this [PdfPageAnnotation], or `None` if no
+    /// interior color is set. Only some annotation types, such as square and circle
+    /// annotations, have an interior.
+    ///
+    /// Pdfium cannot report the color of an annotation that has an appearance stream,
+    /// since the appearance stream's own colors take priority; an error will be returned
+    /// in this case.
+    fn interior_color(&self) -> Result<Option<PdfColor>, PdfiumError>;
+
+    /// Sets the interior color used to fill this [PdfPageAnnotation]. The alpha channel of
+    /// the given color sets the opacity of the annotation.
+    ///
+    /// Pdfium cannot set the color of an annotation that has an appearance stream;
+    /// an error will be returned in this case.
+    fn set_interior_color(&mut self, color: PdfColor) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfPageAnnotationFlags] set on this [PdfPageAnnotation].
+    fn flags(&self) -> PdfPageAnnotationFlags;
+
+    /// Replaces the [PdfPageAnnotationFlags] set on this [PdfPageAnnotation] with the given flags.
+    ///
+    /// For example, to keep an annotation visible on screen while excluding it from
+    /// printed output, remove the [PdfPageAnnotationFlags::PRINT] flag:
+    /// ```
+    /// # use pdfium_render::prelude::*;
+    /// #
+    /// # fn example(annotation: &mut PdfPageAnnotation) -> Result<(), PdfiumError> {
+    /// annotation.set_flags(annotation.flags() - PdfPageAnnotationFlags::PRINT)?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError>;
+
     /// Returns the text to be displayed for this [PdfPageAnnotation], or, if this type of annotation
     /// does not display text, an alternate description of the annotation's contents in human-readable
     /// form. In either case this text is useful when extracting the document's contents in support
@@ -543,6 +680,69 @@ where
         self.bounds_impl()
     }
 
+    #[inline]
+    fn set_bounds(&mut self, bounds: PdfRect) -> Result<(), PdfiumError> {
+        self.set_bounds_impl(bounds)
+    }
+
+    #[inline]
+    fn border(&self) -> Result<(PdfPoints, PdfPoints, PdfPoints), PdfiumError> {
+        self.border_impl()
+    }
+
+    #[inline]
+    fn set_border(
+        &mut self,
+        horizontal_radius: PdfPoints,
+        vertical_radius: PdfPoints,
+        width: PdfPoints,
+    ) -> Result<(), PdfiumError> {
+        self.set_border_impl(horizontal_radius, vertical_radius, width)
+    }
+
+    #[inline]
+    fn color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.get_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, "C")
+    }
+
+    #[inline]
+    fn set_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, color)
+    }
+
+    #[inline]
+    fn interior_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.get_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, "IC")
+    }
+
+    #[inline]
+    fn set_interior_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, color)
+    }
+
+    #[inline]
+    fn flags(&self) -> PdfPageAnnotationFlags {
+        PdfPageAnnotationFlags::from_bits_truncate(
+            self.bindings().FPDFAnnot_GetFlags(*self.handle()) as u32,
+        )
+    }
+
+    #[inline]
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError> {
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDFAnnot_SetFlags(*self.handle(), flags.bits() as c_int),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
     #[inline]
     fn contents(&self) -> Option<String> {
         self.contents_impl()
@@ -1,9 +1,14 @@
 //! Defines the [PdfPageCircleAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::Circle`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_PAGE};
+use crate::bindgen::{
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_PAGE,
+};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
 use crate::document::PdfDocument;
+use crate::error::PdfiumError;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 
@@ -30,6 +35,46 @@ impl<'a> PdfPageCircleAnnotation<'a> {
             ),
         }
     }
+
+    /// Returns the stroke color used to draw the border of this [PdfPageCircleAnnotation],
+    /// or `None` if no stroke color is set.
+    ///
+    /// Pdfium cannot report the color of an annotation that has an appearance stream,
+    /// since the appearance stream's own colors take priority; an error will be returned
+    /// in this case.
+    #[inline]
+    pub fn stroke_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.get_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, "C")
+    }
+
+    /// Sets the stroke color used to draw the border of this [PdfPageCircleAnnotation].
+    ///
+    /// Pdfium cannot set the color of an annotation that has an appearance stream;
+    /// an error will be returned in this case.
+    #[inline]
+    pub fn set_stroke_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, color)
+    }
+
+    /// Returns the interior color used to fill this [PdfPageCircleAnnotation], or `None` if
+    /// no interior color is set, in which case the annotation's interior is transparent.
+    ///
+    /// Pdfium cannot report the color of an annotation that has an appearance stream,
+    /// since the appearance stream's own colors take priority; an error will be returned
+    /// in this case.
+    #[inline]
+    pub fn interior_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.get_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, "IC")
+    }
+
+    /// Sets the interior color used to fill this [PdfPageCircleAnnotation].
+    ///
+    /// Pdfium cannot set the color of an annotation that has an appearance stream;
+    /// an error will be returned in this case.
+    #[inline]
+    pub fn set_interior_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, color)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageCircleAnnotation<'a> {
@@ -1,11 +1,33 @@
 //! Defines the [PdfPageFreeTextAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::FreeText`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_PAGE};
+use crate::bindgen::{
+    FPDF_ANNOTATION, FPDF_ANNOT_APPEARANCEMODE, FPDF_ANNOT_APPEARANCEMODE_NORMAL, FPDF_PAGE,
+};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
 use crate::document::PdfDocument;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page::{PdfPoints, PdfRect};
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use std::fmt::Write;
+
+/// The font used to draw the text of a [PdfPageFreeTextAnnotation] if its default
+/// appearance string does not specify one. Pdfium substitutes its built-in Helvetica font
+/// for any font not present in the annotation's appearance stream resources.
+const DEFAULT_FONT_NAME: &str = "Helv";
+
+/// The font size used to draw the text of a [PdfPageFreeTextAnnotation] if its default
+/// appearance string does not specify one.
+const DEFAULT_FONT_SIZE: f32 = 12.0;
+
+/// The distance between the bounds of a [PdfPageFreeTextAnnotation] and its text.
+const TEXT_PADDING: f32 = 2.0;
+
+/// The distance between successive baselines of text in a [PdfPageFreeTextAnnotation],
+/// as a multiple of the font size.
+const LINE_SPACING: f32 = 1.2;
 
 pub struct PdfPageFreeTextAnnotation<'a> {
     handle: FPDF_ANNOTATION,
@@ -30,6 +52,238 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
             ),
         }
     }
+
+    /// Sets the text displayed by this [PdfPageFreeTextAnnotation], replacing any existing
+    /// text, and regenerates the annotation's appearance stream so the new text is visible
+    /// when the page is next rendered.
+    ///
+    /// The text itself is stored in full, but the generated appearance stream can only draw
+    /// characters in the Latin-1 character set; any other characters are drawn as question
+    /// marks. Line breaks in the text start a new line; long lines are not wrapped, and
+    /// text that does not fit inside the bounds of the annotation is clipped.
+    ///
+    /// The appearance stream is generated using the current bounds of the annotation,
+    /// so if the bounds are subsequently changed, this function should be called again.
+    ///
+    /// **The generated appearance stream refers to the standard Helvetica font by its default
+    /// appearance name, `/Helv`, but does not include a font resource dictionary.** Pdfium
+    /// substitutes a built-in font for the missing resource, so the text renders as expected
+    /// in Pdfium, but other PDF viewers may draw the text in a different font or not at all.
+    /// Pdfium does not provide any way of adding resources to an annotation's appearance stream.
+    pub fn set_contents(&mut self, text: &str) -> Result<(), PdfiumError> {
+        self.set_string_value("Contents", text)?;
+
+        self.regenerate_appearance_stream()
+    }
+
+    /// Returns the font size used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// as specified by its default appearance string. If no font size is specified,
+    /// the default font size of 12 points is returned.
+    #[inline]
+    pub fn font_size(&self) -> PdfPoints {
+        PdfPoints::new(
+            parse_default_appearance(self.get_string_value("DA").unwrap_or_default().as_str())
+                .1
+                .unwrap_or(DEFAULT_FONT_SIZE),
+        )
+    }
+
+    /// Sets the font size used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// and regenerates the annotation's appearance stream.
+    pub fn set_font_size(&mut self, size: PdfPoints) -> Result<(), PdfiumError> {
+        let (font_name, _, color) =
+            parse_default_appearance(self.get_string_value("DA").unwrap_or_default().as_str());
+
+        self.set_default_appearance(font_name, Some(size.value), color)
+    }
+
+    /// Returns the font color used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// as specified by its default appearance string. If no font color is specified,
+    /// [PdfColor::SOLID_BLACK] is returned.
+    #[inline]
+    pub fn font_color(&self) -> PdfColor {
+        parse_default_appearance(self.get_string_value("DA").unwrap_or_default().as_str())
+            .2
+            .unwrap_or(PdfColor::SOLID_BLACK)
+    }
+
+    /// Sets the font color used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// and regenerates the annotation's appearance stream. The alpha channel of the
+    /// given color is ignored.
+    pub fn set_font_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        let (font_name, size, _) =
+            parse_default_appearance(self.get_string_value("DA").unwrap_or_default().as_str());
+
+        self.set_default_appearance(font_name, size, Some(color))
+    }
+
+    /// Replaces the default appearance string of this [PdfPageFreeTextAnnotation] with one
+    /// using the given font name, font size, and font color, then regenerates the
+    /// annotation's appearance stream.
+    fn set_default_appearance(
+        &mut self,
+        font_name: Option<String>,
+        size: Option<f32>,
+        color: Option<PdfColor>,
+    ) -> Result<(), PdfiumError> {
+        self.set_string_value(
+            "DA",
+            create_default_appearance(
+                font_name.as_deref().unwrap_or(DEFAULT_FONT_NAME),
+                size.unwrap_or(DEFAULT_FONT_SIZE),
+                color.unwrap_or(PdfColor::SOLID_BLACK),
+            )
+            .as_str(),
+        )?;
+
+        self.regenerate_appearance_stream()
+    }
+
+    /// Replaces the normal appearance stream of this [PdfPageFreeTextAnnotation] with one
+    /// drawing the annotation's current text using its current default appearance.
+    fn regenerate_appearance_stream(&mut self) -> Result<(), PdfiumError> {
+        let (font_name, size, color) =
+            parse_default_appearance(self.get_string_value("DA").unwrap_or_default().as_str());
+
+        let appearance_stream = create_appearance_stream(
+            self.get_string_value("Contents")
+                .unwrap_or_default()
+                .as_str(),
+            self.bounds_impl()?,
+            font_name.as_deref().unwrap_or(DEFAULT_FONT_NAME),
+            size.unwrap_or(DEFAULT_FONT_SIZE),
+            color.unwrap_or(PdfColor::SOLID_BLACK),
+        );
+
+        if self.bindings.is_true(self.bindings.FPDFAnnot_SetAP_str(
+            self.handle,
+            FPDF_ANNOT_APPEARANCEMODE_NORMAL as FPDF_ANNOT_APPEARANCEMODE,
+            appearance_stream.as_str(),
+        )) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+}
+
+/// Returns the font name, font size, and font color specified by the given default
+/// appearance string, if any. Only gray and RGB font colors are recognised.
+fn parse_default_appearance(
+    default_appearance: &str,
+) -> (Option<String>, Option<f32>, Option<PdfColor>) {
+    let tokens = default_appearance.split_whitespace().collect::<Vec<_>>();
+
+    let mut font_name = None;
+
+    let mut size = None;
+
+    let mut color = None;
+
+    let component = |token: &str| {
+        token
+            .parse::<f32>()
+            .ok()
+            .map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8)
+    };
+
+    for (index, token) in tokens.iter().enumerate() {
+        match *token {
+            "Tf" if index >= 2 => {
+                font_name = tokens[index - 2]
+                    .strip_prefix('/')
+                    .map(|name| name.to_owned());
+                size = tokens[index - 1]
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|size| *size > 0.0);
+            }
+            "g" if index >= 1 => {
+                color =
+                    component(tokens[index - 1]).map(|gray| PdfColor::new(gray, gray, gray, 255));
+            }
+            "rg" if index >= 3 => {
+                if let (Some(red), Some(green), Some(blue)) = (
+                    component(tokens[index - 3]),
+                    component(tokens[index - 2]),
+                    component(tokens[index - 1]),
+                ) {
+                    color = Some(PdfColor::new(red, green, blue, 255));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (font_name, size, color)
+}
+
+/// Returns a default appearance string specifying the given font name, font size, and font color.
+fn create_default_appearance(font_name: &str, size: f32, color: PdfColor) -> String {
+    format!(
+        "/{} {} Tf {} {} {} rg",
+        font_name,
+        size,
+        color.red() as f32 / 255.0,
+        color.green() as f32 / 255.0,
+        color.blue() as f32 / 255.0,
+    )
+}
+
+/// Returns a content stream drawing the given text, line by line, from the top-left corner
+/// of the given bounds, clipped to the bounds.
+fn create_appearance_stream(
+    text: &str,
+    bounds: PdfRect,
+    font_name: &str,
+    size: f32,
+    color: PdfColor,
+) -> String {
+    // Pdfium sets the bounding box of the appearance stream to the bounds of the annotation,
+    // so the appearance stream is drawn in page coordinates.
+
+    let mut result = format!(
+        "q {} {} {} {} re W n BT {} {} TL {} {} Td",
+        bounds.left.value,
+        bounds.bottom.value,
+        bounds.width().value,
+        bounds.height().value,
+        create_default_appearance(font_name, size, color),
+        size * LINE_SPACING,
+        bounds.left.value + TEXT_PADDING,
+        bounds.top.value - TEXT_PADDING - size,
+    );
+
+    for (index, line) in text.replace("\r\n", "\n").split(['\r', '\n']).enumerate() {
+        result.push_str(" (");
+
+        for c in line.chars() {
+            match c {
+                '\\' | '(' | ')' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                ' '..='~' => result.push(c),
+                '\u{a0}'..='\u{ff}' => {
+                    // Escaping non-ASCII characters keeps the content stream in ASCII,
+                    // ensuring each character is encoded as a single byte.
+
+                    let _ = write!(result, "\\{:03o}", c as u32);
+                }
+                _ => result.push('?'),
+            }
+        }
+
+        result.push_str(if index == 0 { ") Tj" } else { ") '" });
+    }
+
+    result.push_str(" ET Q");
+
+    result
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
@@ -53,3 +307,52 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
         &mut self.objects
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_appearance() {
+        // PdfColor does not implement PartialEq, so colors are compared by component.
+
+        let parse = |default_appearance: &str| {
+            let (font_name, size, color) = parse_default_appearance(default_appearance);
+
+            (
+                font_name,
+                size,
+                color.map(|color| (color.red(), color.green(), color.blue())),
+            )
+        };
+
+        assert_eq!(
+            parse("/Helv 9 Tf 1 0 0 rg"),
+            (Some("Helv".to_owned()), Some(9.0), Some((255, 0, 0)))
+        );
+
+        assert_eq!(
+            parse(create_default_appearance("Cour", 14.5, PdfColor::new(0, 0, 255, 255)).as_str()),
+            (Some("Cour".to_owned()), Some(14.5), Some((0, 0, 255)))
+        );
+
+        assert_eq!(parse("0.5 g"), (None, None, Some((128, 128, 128))));
+
+        assert_eq!(parse(""), (None, None, None));
+    }
+
+    #[test]
+    fn test_create_appearance_stream() {
+        assert_eq!(
+            create_appearance_stream(
+                "Caf\u{e9} (1)\r\n\u{2603}",
+                PdfRect::new_from_values(0.0, 10.0, 50.0, 110.0),
+                "Helv",
+                10.0,
+                PdfColor::SOLID_BLACK,
+            ),
+            "q 10 0 100 50 re W n BT /Helv 10 Tf 0 0 0 rg 12 TL 12 38 Td \
+             (Caf\\351 \\(1\\)) Tj (?) ' ET Q"
+        );
+    }
+}
@@ -1,11 +1,17 @@
 //! Defines the [PdfPageInkAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::Ink`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_PAGE};
+use crate::bindgen::{
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, FPDF_ANNOTATION, FPDF_PAGE, FS_POINTF,
+};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
 use crate::document::PdfDocument;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page::{PdfPoints, PdfRect};
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use std::os::raw::c_ulong;
 
 pub struct PdfPageInkAnnotation<'a> {
     handle: FPDF_ANNOTATION,
@@ -36,6 +42,171 @@ impl<'a> PdfPageInkAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Returns the number of ink strokes in this [PdfPageInkAnnotation].
+    #[inline]
+    pub fn ink_stroke_count(&self) -> usize {
+        self.bindings.FPDFAnnot_GetInkListCount(self.handle) as usize
+    }
+
+    /// Returns the points making up the ink stroke at the given index in this
+    /// [PdfPageInkAnnotation], in the order in which they were drawn. Each point is
+    /// expressed as an (x, y) pair in page coordinates.
+    pub fn ink_stroke(&self, index: usize) -> Result<Vec<(PdfPoints, PdfPoints)>, PdfiumError> {
+        if index >= self.ink_stroke_count() {
+            return Err(PdfiumError::InkStrokeIndexOutOfBounds);
+        }
+
+        // Retrieving the stroke points from Pdfium is a two-step operation. First, we call
+        // FPDFAnnot_GetInkListPath() with a null buffer; this will retrieve the number of
+        // points in the stroke. We then allocate a buffer of the given length and call
+        // FPDFAnnot_GetInkListPath() again with a pointer to the buffer.
+
+        let point_count = self.bindings.FPDFAnnot_GetInkListPath(
+            self.handle,
+            index as c_ulong,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if point_count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; point_count as usize];
+
+        let result = self.bindings.FPDFAnnot_GetInkListPath(
+            self.handle,
+            index as c_ulong,
+            buffer.as_mut_ptr(),
+            point_count,
+        );
+
+        assert_eq!(result, point_count);
+
+        Ok(buffer
+            .iter()
+            .map(|point| (PdfPoints::new(point.x), PdfPoints::new(point.y)))
+            .collect())
+    }
+
+    /// Appends a new ink stroke made up of the given points to this [PdfPageInkAnnotation],
+    /// returning the index of the new stroke. Each point is expressed as an (x, y) pair in
+    /// page coordinates, in the order in which the stroke should be drawn.
+    ///
+    /// The bounds of this [PdfPageInkAnnotation] are expanded as necessary to enclose
+    /// the new stroke, allowing for the current stroke width.
+    pub fn add_ink_stroke(
+        &mut self,
+        points: &[(PdfPoints, PdfPoints)],
+    ) -> Result<usize, PdfiumError> {
+        let buffer = points
+            .iter()
+            .map(|(x, y)| FS_POINTF {
+                x: x.value,
+                y: y.value,
+            })
+            .collect::<Vec<_>>();
+
+        let index =
+            self.bindings
+                .FPDFAnnot_AddInkStroke(self.handle, buffer.as_ptr(), buffer.len() as _);
+
+        if index < 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        // Pdfium does not update the annotation's bounds when a stroke is added, so we
+        // expand the bounds ourselves. A newly created annotation has no bounds at all.
+
+        let padding = self.stroke_width().value / 2.0;
+
+        let existing = self
+            .bounds_impl()
+            .ok()
+            .filter(|bounds| bounds.width().value > 0.0 || bounds.height().value > 0.0);
+
+        let bounds = buffer.iter().fold(existing, |bounds, point| {
+            let (bottom, left, top, right) = match bounds {
+                Some(bounds) => (
+                    bounds.bottom.value.min(point.y - padding),
+                    bounds.left.value.min(point.x - padding),
+                    bounds.top.value.max(point.y + padding),
+                    bounds.right.value.max(point.x + padding),
+                ),
+                None => (
+                    point.y - padding,
+                    point.x - padding,
+                    point.y + padding,
+                    point.x + padding,
+                ),
+            };
+
+            Some(PdfRect::new_from_values(bottom, left, top, right))
+        });
+
+        if let Some(bounds) = bounds {
+            self.set_bounds_impl(bounds)?;
+        }
+
+        Ok(index as usize)
+    }
+
+    /// Removes all ink strokes from this [PdfPageInkAnnotation].
+    pub fn remove_ink_strokes(&mut self) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFAnnot_RemoveInkList(self.handle))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns the stroke color used to draw the ink strokes of this [PdfPageInkAnnotation],
+    /// or `None` if no stroke color is set.
+    ///
+    /// Pdfium cannot report the color of an annotation that has an appearance stream,
+    /// since the appearance stream's own colors take priority; an error will be returned
+    /// in this case.
+    #[inline]
+    pub fn stroke_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.get_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, "C")
+    }
+
+    /// Sets the stroke color used to draw the ink strokes of this [PdfPageInkAnnotation].
+    ///
+    /// Pdfium cannot set the color of an annotation that has an appearance stream;
+    /// an error will be returned in this case.
+    #[inline]
+    pub fn set_stroke_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_color_impl(FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, color)
+    }
+
+    /// Returns the width of the ink strokes of this [PdfPageInkAnnotation]. If no border
+    /// is set, the default width of one point is returned.
+    #[inline]
+    pub fn stroke_width(&self) -> PdfPoints {
+        self.border_impl()
+            .map(|(_, _, width)| width)
+            .unwrap_or(PdfPoints::new(1.0))
+    }
+
+    /// Sets the width of the ink strokes of this [PdfPageInkAnnotation]. The stroke width
+    /// is stored as the width of the annotation's border.
+    #[inline]
+    pub fn set_stroke_width(&mut self, width: PdfPoints) -> Result<(), PdfiumError> {
+        self.set_border_impl(PdfPoints::ZERO, PdfPoints::ZERO, width)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
@@ -59,3 +230,74 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
         self.objects_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_ink_stroke_round_trip() -> Result<(), PdfiumError> {
+        // Creates an ink annotation with a single stroke, saves and reloads the document,
+        // and confirms the stroke points and the annotation's bounds are retained.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let points = vec![
+            (PdfPoints::new(100.0), PdfPoints::new(100.0)),
+            (PdfPoints::new(150.0), PdfPoints::new(200.0)),
+            (PdfPoints::new(200.0), PdfPoints::new(120.0)),
+        ];
+
+        let mut annotation = page.annotations_mut().create_ink_annotation()?;
+
+        let ink = annotation.as_ink_annotation_mut().unwrap();
+
+        ink.set_stroke_width(PdfPoints::new(2.0))?;
+        ink.set_stroke_color(PdfColor::SOLID_RED)?;
+
+        assert_eq!(ink.add_ink_stroke(&points)?, 0);
+
+        let color = ink.stroke_color()?.unwrap();
+
+        assert_eq!((color.red(), color.green(), color.blue()), (255, 0, 0));
+
+        drop(annotation);
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotation = page.annotations().get(0)?;
+
+        let ink = annotation.as_ink_annotation().unwrap();
+
+        assert_eq!(ink.ink_stroke_count(), 1);
+        assert_eq!(ink.ink_stroke(0)?, points);
+        assert_eq!(ink.stroke_width(), PdfPoints::new(2.0));
+
+        // The bounds enclose the stroke, padded by half the stroke width.
+
+        let bounds = annotation.bounds()?;
+
+        assert_eq!(
+            (
+                bounds.left.value,
+                bounds.bottom.value,
+                bounds.right.value,
+                bounds.top.value
+            ),
+            (99.0, 99.0, 201.0, 201.0)
+        );
+
+        Ok(())
+    }
+}
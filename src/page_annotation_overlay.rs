@@ -0,0 +1,81 @@
+//! Defines the [PdfPageAnnotationOverlay] struct, a transparent bitmap containing a single
+//! rendered annotation, together with its placement over a rendered `PdfPage`.
+
+use crate::bitmap::{PdfBitmap, Pixels};
+use crate::page_annotations::PdfPageAnnotationIndex;
+
+/// A single annotation rendered onto a transparent background, as returned by the
+/// `PdfPage::render_annotation_overlays()` function.
+///
+/// The overlay bitmap covers only the bounds of its annotation. Its placement is measured
+/// in pixels from the top-left corner of a bitmap rendered from the same page using the same
+/// `PdfRenderConfig`, so the overlay can be drawn directly on top of the rendered page, and
+/// shown or hidden independently of any other overlays.
+pub struct PdfPageAnnotationOverlay<'a> {
+    index: PdfPageAnnotationIndex,
+    bitmap: PdfBitmap<'a>,
+    left: i32,
+    top: i32,
+}
+
+impl<'a> PdfPageAnnotationOverlay<'a> {
+    #[inline]
+    pub(crate) fn new(
+        index: PdfPageAnnotationIndex,
+        bitmap: PdfBitmap<'a>,
+        left: i32,
+        top: i32,
+    ) -> Self {
+        PdfPageAnnotationOverlay {
+            index,
+            bitmap,
+            left,
+            top,
+        }
+    }
+
+    /// Returns the index of the rendered annotation in the page's `PdfPageAnnotations` collection.
+    #[inline]
+    pub fn index(&self) -> PdfPageAnnotationIndex {
+        self.index
+    }
+
+    /// Returns the transparent [PdfBitmap] containing the rendered annotation.
+    #[inline]
+    pub fn bitmap(&self) -> &PdfBitmap<'a> {
+        &self.bitmap
+    }
+
+    /// Consumes this [PdfPageAnnotationOverlay], returning the [PdfBitmap] containing
+    /// the rendered annotation.
+    #[inline]
+    pub fn into_bitmap(self) -> PdfBitmap<'a> {
+        self.bitmap
+    }
+
+    /// Returns the horizontal position, in pixels, of the left edge of this overlay
+    /// relative to the left edge of the rendered page.
+    #[inline]
+    pub fn left(&self) -> i32 {
+        self.left
+    }
+
+    /// Returns the vertical position, in pixels, of the top edge of this overlay
+    /// relative to the top edge of the rendered page.
+    #[inline]
+    pub fn top(&self) -> i32 {
+        self.top
+    }
+
+    /// Returns the width, in pixels, of this overlay.
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.bitmap.width()
+    }
+
+    /// Returns the height, in pixels, of this overlay.
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.bitmap.height()
+    }
+}
@@ -7,10 +7,13 @@ pub(crate) mod internal {
     // Instead of making the PdfPageAnnotationPrivate trait private, we leave it public but place it
     // inside this pub(crate) module in order to prevent it from being visible outside the crate.
 
-    use crate::bindgen::{FPDF_ANNOTATION, FPDF_OBJECT_STRING, FPDF_WCHAR, FS_RECTF};
+    use crate::bindgen::{
+        FPDFANNOT_COLORTYPE, FPDF_ANNOTATION, FPDF_OBJECT_STRING, FPDF_WCHAR, FS_RECTF,
+    };
     use crate::bindings::PdfiumLibraryBindings;
-    use crate::error::PdfiumError;
-    use crate::page::PdfRect;
+    use crate::color::PdfColor;
+    use crate::error::{PdfiumError, PdfiumInternalError};
+    use crate::page::{PdfPoints, PdfRect};
     use crate::page_annotation::PdfPageAnnotationCommon;
     use crate::page_annotation_objects::PdfPageAnnotationObjects;
     use crate::utils::mem::create_byte_buffer;
@@ -81,6 +84,92 @@ pub(crate) mod internal {
             Some(get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default())
         }
 
+        /// Sets the string value associated with the given key in the annotation dictionary
+        /// of this [PdfPageAnnotation], replacing any existing value.
+        fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+            if self
+                .bindings()
+                .is_true(
+                    self.bindings()
+                        .FPDFAnnot_SetStringValue_str(*self.handle(), key, value),
+                )
+            {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
+        /// Returns the color of the given type set in the annotation dictionary of this
+        /// [PdfPageAnnotation], if any. The dictionary key corresponding to the color type,
+        /// either `C` or `IC`, must also be given, since Pdfium reports a default color
+        /// rather than failing when no color of the given type is set.
+        fn get_color_impl(
+            &self,
+            color_type: FPDFANNOT_COLORTYPE,
+            key: &str,
+        ) -> Result<Option<PdfColor>, PdfiumError> {
+            if !self
+                .bindings()
+                .is_true(self.bindings().FPDFAnnot_HasKey(*self.handle(), key))
+            {
+                return Ok(None);
+            }
+
+            let mut r = 0;
+
+            let mut g = 0;
+
+            let mut b = 0;
+
+            let mut a = 0;
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_GetColor(
+                *self.handle(),
+                color_type,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )) {
+                Ok(Some(PdfColor::new(r as u8, g as u8, b as u8, a as u8)))
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
+        /// Sets the color of the given type in the annotation dictionary of this [PdfPageAnnotation].
+        fn set_color_impl(
+            &mut self,
+            color_type: FPDFANNOT_COLORTYPE,
+            color: PdfColor,
+        ) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetColor(
+                *self.handle(),
+                color_type,
+                color.red() as u32,
+                color.green() as u32,
+                color.blue() as u32,
+                color.alpha() as u32,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::name()].
         #[inline]
         fn name_impl(&self) -> Option<String> {
@@ -102,6 +191,80 @@ pub(crate) mod internal {
             PdfRect::from_pdfium_as_result(result, rect, self.bindings())
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::set_bounds()].
+        fn set_bounds_impl(&mut self, bounds: PdfRect) -> Result<(), PdfiumError> {
+            let rect = FS_RECTF {
+                left: bounds.left.value,
+                bottom: bounds.bottom.value,
+                right: bounds.right.value,
+                top: bounds.top.value,
+            };
+
+            if self
+                .bindings()
+                .is_true(self.bindings().FPDFAnnot_SetRect(*self.handle(), &rect))
+            {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::border()].
+        fn border_impl(&self) -> Result<(PdfPoints, PdfPoints, PdfPoints), PdfiumError> {
+            let mut horizontal_radius = 0.0;
+
+            let mut vertical_radius = 0.0;
+
+            let mut width = 0.0;
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_GetBorder(
+                *self.handle(),
+                &mut horizontal_radius,
+                &mut vertical_radius,
+                &mut width,
+            )) {
+                Ok((
+                    PdfPoints::new(horizontal_radius),
+                    PdfPoints::new(vertical_radius),
+                    PdfPoints::new(width),
+                ))
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_border()].
+        fn set_border_impl(
+            &mut self,
+            horizontal_radius: PdfPoints,
+            vertical_radius: PdfPoints,
+            width: PdfPoints,
+        ) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetBorder(
+                *self.handle(),
+                horizontal_radius.value,
+                vertical_radius.value,
+                width.value,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    self.bindings()
+                        .get_pdfium_last_error()
+                        .unwrap_or(PdfiumInternalError::Unknown),
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::contents()].
         #[inline]
         fn contents_impl(&self) -> Option<String> {
@@ -1,11 +1,12 @@
 //! Defines the [PdfPageAnnotations] struct, exposing functionality related to the
 //! annotations that have been added to a single `PdfPage`.
 
-use crate::bindgen::FPDF_PAGE;
+use crate::bindgen::{FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_INK, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::page_annotation::PdfPageAnnotation;
+use crate::page::PdfRect;
+use crate::page_annotation::{PdfPageAnnotation, PdfPageAnnotationCommon};
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 use std::ops::Range;
 use std::os::raw::c_int;
@@ -102,6 +103,113 @@ impl<'a> PdfPageAnnotations<'a> {
         }
     }
 
+    /// Returns the index of the given [PdfPageAnnotation] in this [PdfPageAnnotations] collection,
+    /// if the annotation is attached to the containing `PdfPage`.
+    ///
+    /// Annotation indices are assigned in the order in which annotations were added to the page.
+    /// The index of an annotation will change if an annotation with a lower index is removed.
+    ///
+    /// Pdfium finds the annotation by searching the page's list of annotations in order, so
+    /// the cost of this function is proportional to the number of annotations on the page.
+    /// To find the indices of many annotations, iterate over this collection once instead.
+    #[inline]
+    pub fn index_of(&self, annotation: &PdfPageAnnotation) -> Option<PdfPageAnnotationIndex> {
+        match self
+            .bindings()
+            .FPDFPage_GetAnnotIndex(self.page_handle, *annotation.handle())
+        {
+            -1 => None,
+            index => Some(index as PdfPageAnnotationIndex),
+        }
+    }
+
+    /// Creates a new, empty `PdfPageInkAnnotation` on the containing `PdfPage`, returning it
+    /// wrapped in a [PdfPageAnnotation]. Use `PdfPageAnnotation::as_ink_annotation_mut()`
+    /// to add ink strokes to the new annotation.
+    ///
+    /// The annotation is added to the page immediately, and is saved along with the
+    /// rest of the containing `PdfDocument`.
+    #[inline]
+    pub fn create_ink_annotation(&mut self) -> Result<PdfPageAnnotation<'a>, PdfiumError> {
+        self.create_annotation(FPDF_ANNOT_INK)
+    }
+
+    /// Creates a new `PdfPageFreeTextAnnotation` displaying the given text inside the given
+    /// bounds on the containing `PdfPage`, returning it wrapped in a [PdfPageAnnotation].
+    /// Use `PdfPageAnnotation::as_free_text_annotation_mut()` to change the text, font size,
+    /// or font color of the new annotation.
+    ///
+    /// The annotation is added to the page immediately, and is saved along with the
+    /// rest of the containing `PdfDocument`.
+    ///
+    /// The annotation's appearance stream is generated as described for
+    /// `PdfPageFreeTextAnnotation::set_contents()`. Because Pdfium cannot add a font resource
+    /// to the appearance stream, the text renders as expected in Pdfium but may render in a
+    /// different font, or not at all, in other PDF viewers.
+    pub fn create_free_text_annotation(
+        &mut self,
+        bounds: PdfRect,
+        text: &str,
+    ) -> Result<PdfPageAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_annotation(FPDF_ANNOT_FREETEXT)?;
+
+        if let PdfPageAnnotation::FreeText(free_text) = &mut annotation {
+            free_text.set_bounds(bounds)?;
+            free_text.set_contents(text)?;
+        }
+
+        Ok(annotation)
+    }
+
+    /// Deletes the annotation at the given index from this [PdfPageAnnotations] collection.
+    ///
+    /// The indices of all annotations following the deleted annotation are reduced by one.
+    /// To delete several annotations by index, delete them in descending order of index.
+    pub fn delete_at_index(&mut self, index: PdfPageAnnotationIndex) -> Result<(), PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+        }
+
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDFPage_RemoveAnnot(self.page_handle, index as c_int),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Creates a new annotation of the given subtype on the containing `PdfPage`.
+    fn create_annotation(&mut self, subtype: u32) -> Result<PdfPageAnnotation<'a>, PdfiumError> {
+        let annotation_handle = self
+            .bindings()
+            .FPDFPage_CreateAnnot(self.page_handle, subtype as FPDF_ANNOTATION_SUBTYPE);
+
+        if annotation_handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            let mut annotation =
+                PdfPageAnnotation::from_pdfium(annotation_handle, self.page_handle, self.document);
+
+            annotation
+                .objects_mut_impl()
+                .do_regenerate_page_content_after_each_change(
+                    self.do_regenerate_page_content_after_each_change,
+                );
+
+            Ok(annotation)
+        }
+    }
+
     /// Returns an iterator over all the annotations in this [PdfPageAnnotations] collection.
     #[inline]
     pub fn iter(&self) -> PdfPageAnnotationsIterator {
@@ -136,3 +244,137 @@ impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_delete_at_index() -> Result<(), PdfiumError> {
+        // Creates three annotations with distinct contents, deletes the middle one, and
+        // confirms that the remaining annotations keep their relative order.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for text in ["First", "Second", "Third"] {
+            page.annotations_mut().create_free_text_annotation(
+                PdfRect::new_from_values(100.0, 100.0, 200.0, 300.0),
+                text,
+            )?;
+        }
+
+        assert_eq!(page.annotations().len(), 3);
+
+        page.annotations_mut().delete_at_index(1)?;
+
+        assert_eq!(page.annotations().len(), 2);
+
+        assert_eq!(
+            page.annotations()
+                .iter()
+                .map(|annotation| annotation.contents())
+                .collect::<Vec<_>>(),
+            vec![Some("First".to_owned()), Some("Third".to_owned())]
+        );
+
+        assert!(page.annotations_mut().delete_at_index(2).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_free_text_annotation_renders_text() -> Result<(), PdfiumError> {
+        // Renders a page containing only a free text annotation, and confirms that the
+        // annotation's generated appearance stream draws something inside its bounds.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let bounds = PdfRect::new_from_values(700.0, 50.0, 760.0, 300.0);
+
+        page.annotations_mut()
+            .create_free_text_annotation(bounds, "Hello, world")?;
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .set_clear_color(PdfColor::SOLID_WHITE)
+                .render_annotations(true),
+        )?;
+
+        let scale = bitmap.width() as f32 / page.width().value;
+
+        let stride = bitmap.stride();
+
+        let bytes = bitmap.as_bytes();
+
+        let rows = ((page.height() - bounds.top).value * scale) as usize
+            ..((page.height() - bounds.bottom).value * scale) as usize;
+
+        let columns = (bounds.left.value * scale) as usize..(bounds.right.value * scale) as usize;
+
+        let is_text_drawn = rows.into_iter().any(|row| {
+            bytes[row * stride + columns.start * 4..row * stride + columns.end * 4]
+                .iter()
+                .any(|byte| *byte != 255)
+        });
+
+        assert!(is_text_drawn);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flags_persist_after_save() -> Result<(), PdfiumError> {
+        // Creates an annotation that is printed, removes the print flag, and confirms that
+        // the change survives saving and reloading the document.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_free_text_annotation(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 300.0),
+            "Not printed",
+        )?;
+
+        annotation.set_flags(PdfPageAnnotationFlags::PRINT | PdfPageAnnotationFlags::NO_ROTATE)?;
+
+        assert!(annotation.flags().contains(PdfPageAnnotationFlags::PRINT));
+
+        annotation.set_flags(annotation.flags() - PdfPageAnnotationFlags::PRINT)?;
+
+        drop(annotation);
+
+        drop(page);
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let annotation = page.annotations().get(0)?;
+
+        assert!(!annotation.flags().contains(PdfPageAnnotationFlags::PRINT));
+        assert!(annotation
+            .flags()
+            .contains(PdfPageAnnotationFlags::NO_ROTATE));
+
+        Ok(())
+    }
+}
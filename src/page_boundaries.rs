@@ -255,9 +255,13 @@ impl<'a> PdfPageBoundaries<'a> {
     }
 
     /// Returns the Bounding box ("BBox") defined for the containing `PdfPage`, if any.
-    /// The BBox is the smallest rectangle that can enclose all the content contained in the page.
-    /// Unlike other boundary boxes, the BBox is computed dynamically on request and cannot
-    /// be set explicitly.
+    /// Pdfium computes the BBox as the intersection of the page's MediaBox and CropBox,
+    /// i.e. the visible area of the page. Unlike other boundary boxes, the BBox is computed
+    /// dynamically on request and cannot be set explicitly.
+    ///
+    /// The BBox does not reflect the extent of the content drawn on the page. To retrieve
+    /// the smallest rectangle enclosing the page's content, use the
+    /// `PdfPage::content_bounding_box()` function.
     #[inline]
     pub fn bounding(&self) -> Result<PdfPageBoundaryBox, PdfiumError> {
         let mut rect = FS_RECTF {
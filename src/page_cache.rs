@@ -0,0 +1,155 @@
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::page_index_cache::PdfPageIndexCache;
+use crate::pages::PdfPageIndex;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// A page loaded by Pdfium and retained by a [PdfPageCache].
+///
+/// Every `PdfPage` retrieved from the cache shares the same [PdfCachedPage]. The underlying
+/// `FPDF_PAGE` handle is closed only once the page has been evicted from the cache and the
+/// last `PdfPage` using it has been dropped, so an evicted page remains valid for as long as
+/// it is in use.
+pub(crate) struct PdfCachedPage {
+    handle: FPDF_PAGE,
+    is_evicted: Cell<bool>,
+}
+
+impl PdfCachedPage {
+    /// Returns the internal `FPDF_PAGE` handle for this [PdfCachedPage].
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_PAGE {
+        self.handle
+    }
+
+    /// Releases the given reference to a [PdfCachedPage], closing the page if it has been
+    /// evicted from its cache and this is the last remaining reference to it.
+    pub(crate) fn release(
+        page: Rc<PdfCachedPage>,
+        document: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) {
+        if page.is_evicted.get() && Rc::strong_count(&page) == 1 {
+            bindings.FPDF_ClosePage(page.handle);
+
+            PdfPageIndexCache::remove_index_for_page(document, page.handle);
+        }
+    }
+}
+
+/// A least-recently-used cache of pages loaded from a single `PdfDocument`, allowing
+/// repeated retrieval of the same page to reuse a single `FPDF_PAGE` handle rather than
+/// reloading and re-parsing the page each time.
+///
+/// Cached pages are keyed by the index at which they were loaded. Inserting, deleting, or
+/// importing pages shifts the indices of the pages that follow, so the cache must be
+/// invalidated by calling [PdfPageCache::invalidate()] whenever the document's pages change.
+///
+/// The cache is disabled, with a capacity of zero, until enabled by calling
+/// `PdfPages::with_cache()`.
+pub(crate) struct PdfPageCache {
+    capacity: usize,
+    pages: VecDeque<(PdfPageIndex, Rc<PdfCachedPage>)>,
+}
+
+impl PdfPageCache {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        PdfPageCache {
+            capacity: 0,
+            pages: VecDeque::new(),
+        }
+    }
+
+    /// Sets the maximum number of pages retained by this [PdfPageCache], evicting the
+    /// least recently used pages if necessary. A capacity of zero disables the cache.
+    pub(crate) fn set_capacity(
+        &mut self,
+        capacity: usize,
+        document: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) {
+        self.capacity = capacity;
+
+        self.evict(self.capacity, document, bindings);
+    }
+
+    /// Returns the cached page loaded at the given index, if any, marking it as the
+    /// most recently used page.
+    pub(crate) fn get(&mut self, index: PdfPageIndex) -> Option<Rc<PdfCachedPage>> {
+        let position = self
+            .pages
+            .iter()
+            .position(|(page_index, _)| *page_index == index)?;
+
+        let entry = self.pages.remove(position)?;
+
+        let page = entry.1.clone();
+
+        self.pages.push_back(entry);
+
+        Some(page)
+    }
+
+    /// Adds the given page handle, loaded at the given index, to this [PdfPageCache] as the
+    /// most recently used page, evicting the least recently used page if the cache is full.
+    /// Returns `None`, leaving ownership of the handle with the caller, if the cache is disabled.
+    pub(crate) fn insert(
+        &mut self,
+        index: PdfPageIndex,
+        handle: FPDF_PAGE,
+        document: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Option<Rc<PdfCachedPage>> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let page = Rc::new(PdfCachedPage {
+            handle,
+            is_evicted: Cell::new(false),
+        });
+
+        self.pages.push_back((index, page.clone()));
+
+        self.evict(self.capacity, document, bindings);
+
+        Some(page)
+    }
+
+    /// Evicts all pages from this [PdfPageCache] without changing its capacity. This must be
+    /// called whenever pages are inserted into or deleted from the document, since the
+    /// indices at which the cached pages were loaded may no longer be correct.
+    #[inline]
+    pub(crate) fn invalidate(
+        &mut self,
+        document: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) {
+        self.evict(0, document, bindings);
+    }
+
+    /// Evicts all pages from this [PdfPageCache] and disables it.
+    #[inline]
+    pub(crate) fn clear(&mut self, document: FPDF_DOCUMENT, bindings: &dyn PdfiumLibraryBindings) {
+        self.set_capacity(0, document, bindings);
+    }
+
+    /// Evicts the least recently used pages until no more than the given number of pages remain.
+    fn evict(
+        &mut self,
+        retain: usize,
+        document: FPDF_DOCUMENT,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) {
+        while self.pages.len() > retain {
+            if let Some((_, page)) = self.pages.pop_front() {
+                page.is_evicted.set(true);
+
+                PdfCachedPage::release(page, document, bindings);
+            }
+        }
+    }
+}
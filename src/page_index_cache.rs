@@ -13,7 +13,7 @@ static PAGE_INDEX_CACHE: Lazy<Mutex<PdfPageIndexCache>> =
 
 pub(crate) struct PdfPageIndexCache {
     pages_by_index: HashMap<(FPDF_DOCUMENT, FPDF_PAGE), PdfPageIndex>,
-    indices_by_page: HashMap<(FPDF_DOCUMENT, PdfPageIndex), FPDF_PAGE>,
+    indices_by_page: HashMap<(FPDF_DOCUMENT, PdfPageIndex), Vec<FPDF_PAGE>>,
     documents_by_maximum_index: HashMap<FPDF_DOCUMENT, PdfPageIndex>,
 }
 
@@ -37,7 +37,15 @@ impl PdfPageIndexCache {
     #[inline]
     fn set(&mut self, document: FPDF_DOCUMENT, page: FPDF_PAGE, index: PdfPageIndex) {
         self.pages_by_index.insert((document, page), index);
-        self.indices_by_page.insert((document, index), page);
+
+        // More than one handle to the same page may be open at once, so we track every
+        // handle at each index.
+
+        let pages = self.indices_by_page.entry((document, index)).or_default();
+
+        if !pages.contains(&page) {
+            pages.push(page);
+        }
 
         // Keep track of the maximum page index for this document. We'll need to know this
         // if we have to shuffle indices to accommodate page insertions or deletions.
@@ -58,6 +66,16 @@ impl PdfPageIndexCache {
     #[inline]
     fn remove(&mut self, document: FPDF_DOCUMENT, page: FPDF_PAGE) {
         if let Some(index) = self.pages_by_index.remove(&(document, page)) {
+            if let Some(pages) = self.indices_by_page.get_mut(&(document, index)) {
+                pages.retain(|other| *other != page);
+
+                if !pages.is_empty() {
+                    // Another handle to this page is still open, so the index remains in use.
+
+                    return;
+                }
+            }
+
             self.indices_by_page.remove(&(document, index));
 
             if self.documents_by_maximum_index.get(&document).copied() == Some(index) {
@@ -93,15 +111,17 @@ impl PdfPageIndexCache {
     fn insert(&mut self, document: FPDF_DOCUMENT, index: PdfPageIndex, count: PdfPageIndex) {
         match self.documents_by_maximum_index.get(&document).copied() {
             Some(maximum_index_for_document) => {
-                if maximum_index_for_document > index {
-                    // Shuffle down all page indices in the document after the given index position.
+                if maximum_index_for_document >= index {
+                    // Shuffle down all page indices in the document at or after the given index position.
 
                     for index in (index..=maximum_index_for_document).rev() {
-                        if let Some(page) = self.indices_by_page.get(&(document, index)).copied() {
-                            // Update the indices of this page.
+                        if let Some(pages) = self.indices_by_page.get(&(document, index)).cloned() {
+                            // Update the indices of every handle to this page.
 
-                            self.remove(document, page);
-                            self.set(document, page, index + count);
+                            for page in pages {
+                                self.remove(document, page);
+                                self.set(document, page, index + count);
+                            }
                         }
                     }
                 }
@@ -133,8 +153,10 @@ impl PdfPageIndexCache {
         // Remove the deleted pages from the cache.
 
         for index in index..index + count {
-            if let Some(page) = self.indices_by_page.get(&(document, index)).copied() {
-                self.remove(document, page);
+            if let Some(pages) = self.indices_by_page.get(&(document, index)).cloned() {
+                for page in pages {
+                    self.remove(document, page);
+                }
             }
         }
 
@@ -142,11 +164,13 @@ impl PdfPageIndexCache {
             // Shuffle up all page indices in the document after the given index position.
 
             for index in index + 1..=maximum_index_for_document {
-                if let Some(page) = self.indices_by_page.get(&(document, index)).copied() {
-                    // Update the indices of this page.
+                if let Some(pages) = self.indices_by_page.get(&(document, index)).cloned() {
+                    // Update the indices of every handle to this page.
 
-                    self.remove(document, page);
-                    self.set(document, page, index - count);
+                    for page in pages {
+                        self.remove(document, page);
+                        self.set(document, page, index - count);
+                    }
                 }
             }
         } else {
@@ -898,4 +922,37 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_multiple_handles_to_same_page() -> Result<(), PdfiumError> {
+        // Confirms that closing one of two open handles to the same page does not stop
+        // the index of the other handle from being updated when pages are inserted.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        document
+            .pages()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let first = document.pages().get(0)?;
+
+        let second = document.pages().get(0)?;
+
+        assert_ne!(first.handle(), second.handle());
+
+        drop(first);
+
+        document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert_eq!(
+            PdfPageIndexCache::get_index_for_page(*document.handle(), *second.handle()),
+            Some(1)
+        );
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 //! Defines the [PdfPageObject] enum, exposing functionality related to a single page object.
 
 use crate::bindgen::{
-    FPDF_ANNOTATION, FPDF_LINECAP_BUTT, FPDF_LINECAP_PROJECTING_SQUARE, FPDF_LINECAP_ROUND,
+    size_t, FPDF_ANNOTATION, FPDF_LINECAP_BUTT, FPDF_LINECAP_PROJECTING_SQUARE, FPDF_LINECAP_ROUND,
     FPDF_LINEJOIN_BEVEL, FPDF_LINEJOIN_MITER, FPDF_LINEJOIN_ROUND, FPDF_PAGE, FPDF_PAGEOBJECT,
     FPDF_PAGEOBJ_FORM, FPDF_PAGEOBJ_IMAGE, FPDF_PAGEOBJ_PATH, FPDF_PAGEOBJ_SHADING,
     FPDF_PAGEOBJ_TEXT, FPDF_PAGEOBJ_UNKNOWN,
@@ -20,6 +20,7 @@ use crate::page_object_shading::PdfPageShadingObject;
 use crate::page_object_text::PdfPageTextObject;
 use crate::page_object_unsupported::PdfPageUnsupportedObject;
 use crate::page_objects::PdfPageObjects;
+use crate::transform::PdfMatrix;
 use std::convert::TryInto;
 use std::os::raw::{c_int, c_uint};
 
@@ -489,6 +490,34 @@ pub trait PdfPageObjectCommon<'a> {
     /// any glyphs, use the [PdfPageTextObject::descent()] function.
     fn bounds(&self) -> Result<PdfRect, PdfiumError>;
 
+    /// Returns the four corner points of the bounding quadrilateral of this [PdfPageObject],
+    /// taking into account any rotation or skew applied by the object's transformation matrix.
+    ///
+    /// Unlike the axis-aligned rectangle returned by [PdfPageObjectCommon::bounds()], which
+    /// encloses the whole of a rotated object and so overstates its footprint, the returned
+    /// quadrilateral tightly follows the object's own axes. The corners are returned in the
+    /// order bottom-left, bottom-right, top-right, top-left, relative to the object's
+    /// untransformed orientation.
+    ///
+    /// The corners are calculated by mapping the bounds of the object in its own coordinate
+    /// space through its transformation matrix; the object itself is not modified.
+    /// Image and form fragment objects are measured exactly. Path objects are measured from
+    /// their segment points, so the stroke width is not included. Text objects are
+    /// approximated from their axis-aligned bounds on the assumption that the text begins at
+    /// the origin of its text matrix. For all other objects, the corners of the axis-aligned
+    /// bounds returned by [PdfPageObjectCommon::bounds()] are returned.
+    fn rotated_bounds(&self) -> Result<[(PdfPoints, PdfPoints); 4], PdfiumError>;
+
+    /// Returns the name of the optional content group ("layer") this [PdfPageObject] belongs to,
+    /// if any. Objects belonging to an optional content group are hidden when that layer
+    /// is turned off in a viewer.
+    ///
+    /// Membership is determined from the `/OC` marked content sequence enclosing the object
+    /// in the page's content stream. If the object is not enclosed in such a sequence, or if
+    /// the sequence refers to an optional content membership dictionary rather than a single
+    /// named optional content group, then `None` will be returned.
+    fn optional_content_group(&self) -> Option<String>;
+
     /// Returns the width of this [PdfPageObject].
     #[inline]
     fn width(&self) -> Result<PdfPoints, PdfiumError> {
@@ -559,6 +588,13 @@ pub trait PdfPageObjectCommon<'a> {
     /// given [PdfPageObject] will be immediately applied to this [PdfPageObject].
     fn transform_from(&mut self, other: &PdfPageObject) -> Result<(), PdfiumError>;
 
+    /// Returns the transformation matrix currently applied to this [PdfPageObject].
+    ///
+    /// The matrix maps the object's own coordinate space onto page coordinates. For a
+    /// `PdfPageImageObject`, whose image is always drawn into a unit square, the matrix
+    /// describes the position, size, rotation, and skew of the image as placed on the page.
+    fn get_matrix(&self) -> Result<PdfMatrix, PdfiumError>;
+
     /// Moves the origin of this [PdfPageObject] by the given horizontal and vertical delta distances.
     #[inline]
     fn translate(&mut self, delta_x: PdfPoints, delta_y: PdfPoints) -> Result<(), PdfiumError> {
@@ -816,6 +852,30 @@ pub trait PdfPageObjectCommon<'a> {
     /// in this [PdfPageObject].
     fn set_line_cap(&mut self, line_cap: PdfPageObjectLineCap) -> Result<(), PdfiumError>;
 
+    /// Returns the lengths of the alternating dashes and gaps used when painting stroked
+    /// lines in this [PdfPageObject]. An empty array denotes a solid line.
+    fn dash_array(&self) -> Result<Vec<PdfPoints>, PdfiumError>;
+
+    /// Returns the distance into the dash pattern at which the dash pattern starts when
+    /// painting stroked lines in this [PdfPageObject].
+    fn dash_phase(&self) -> Result<PdfPoints, PdfiumError>;
+
+    /// Sets the dash pattern used when painting stroked lines in this [PdfPageObject].
+    ///
+    /// The given array specifies the lengths of alternating dashes and gaps, starting with
+    /// a dash; for example, `[3.0, 2.0]` paints dashes 3 points long separated by gaps 2 points
+    /// long. If the array contains an odd number of lengths, it is repeated to form the pattern.
+    /// The given phase specifies the distance into the dash pattern at which to start the pattern.
+    /// An empty array denotes a solid line.
+    ///
+    /// An error is returned if any length in the given array is negative, or if every
+    /// length in a non-empty array is zero.
+    fn set_dash_pattern(
+        &mut self,
+        dash_array: &[PdfPoints],
+        phase: PdfPoints,
+    ) -> Result<(), PdfiumError>;
+
     /// Returns `true` if this [PdfPageObject] can be successfully copied by calling its
     /// `try_copy()` function.
     ///
@@ -876,6 +936,16 @@ where
         self.bounds_impl()
     }
 
+    #[inline]
+    fn rotated_bounds(&self) -> Result<[(PdfPoints, PdfPoints); 4], PdfiumError> {
+        self.rotated_bounds_impl()
+    }
+
+    #[inline]
+    fn optional_content_group(&self) -> Option<String> {
+        self.optional_content_group_impl()
+    }
+
     #[inline]
     fn transform(
         &mut self,
@@ -894,6 +964,11 @@ where
         self.set_matrix(other.matrix()?)
     }
 
+    #[inline]
+    fn get_matrix(&self) -> Result<PdfMatrix, PdfiumError> {
+        self.matrix()
+    }
+
     #[inline]
     fn get_horizontal_translation(&self) -> PdfPoints {
         self.get_horizontal_translation_impl()
@@ -1113,6 +1188,65 @@ where
         }
     }
 
+    fn dash_array(&self) -> Result<Vec<PdfPoints>, PdfiumError> {
+        let count = self
+            .bindings()
+            .FPDFPageObj_GetDashCount(*self.get_object_handle());
+
+        if count < 0 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
+        let mut dash_array = vec![0.0; count as usize];
+
+        if self
+            .bindings()
+            .is_true(self.bindings().FPDFPageObj_GetDashArray(
+                *self.get_object_handle(),
+                dash_array.as_mut_ptr(),
+                count as size_t,
+            ))
+        {
+            Ok(dash_array.into_iter().map(PdfPoints::new).collect())
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    #[inline]
+    fn dash_phase(&self) -> Result<PdfPoints, PdfiumError> {
+        let mut phase = 0.0;
+
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDFPageObj_GetDashPhase(*self.get_object_handle(), &mut phase),
+        ) {
+            Ok(PdfPoints::new(phase))
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    fn set_dash_pattern(
+        &mut self,
+        dash_array: &[PdfPoints],
+        phase: PdfPoints,
+    ) -> Result<(), PdfiumError> {
+        if dash_array.iter().any(|length| length.value < 0.0)
+            || (!dash_array.is_empty() && dash_array.iter().all(|length| length.value == 0.0))
+        {
+            return Err(PdfiumError::InvalidDashPattern);
+        }
+
+        self.set_dash_pattern_unchecked_impl(
+            &dash_array
+                .iter()
+                .map(|length| length.value)
+                .collect::<Vec<_>>(),
+            phase.value,
+        )
+    }
+
     #[inline]
     fn is_copyable(&self) -> bool {
         self.is_cloneable_impl()
@@ -1206,6 +1340,11 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageObject<'a> {
     ) -> Result<PdfPageObject<'b>, PdfiumError> {
         self.unwrap_as_trait().try_copy(document)
     }
+
+    #[inline]
+    fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+        self.unwrap_as_trait().untransformed_bounds_impl()
+    }
 }
 
 impl<'a> From<PdfPageFormFragmentObject<'a>> for PdfPageObject<'a> {
@@ -5,8 +5,10 @@ use crate::bindgen::{FPDF_ANNOTATION, FPDF_PAGE, FPDF_PAGEOBJECT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::document::PdfDocument;
 use crate::error::PdfiumError;
+use crate::page::PdfRect;
 use crate::page_object::PdfPageObject;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
+use std::os::raw::c_ulong;
 
 pub struct PdfPageFormFragmentObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
@@ -72,6 +74,52 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageFormFragmentObject<'a> {
         self.bindings
     }
 
+    fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+        // The bounds of the objects inside a form are reported by Pdfium in the form's
+        // own coordinate space.
+
+        let count = self.bindings.FPDFFormObj_CountObjects(self.object_handle);
+
+        (0..count.max(0) as c_ulong)
+            .filter_map(|index| {
+                let child = self
+                    .bindings
+                    .FPDFFormObj_GetObject(self.object_handle, index);
+
+                if child.is_null() {
+                    return None;
+                }
+
+                let mut left = 0.0;
+
+                let mut bottom = 0.0;
+
+                let mut right = 0.0;
+
+                let mut top = 0.0;
+
+                if self.bindings.is_true(self.bindings.FPDFPageObj_GetBounds(
+                    child,
+                    &mut left,
+                    &mut bottom,
+                    &mut right,
+                    &mut top,
+                )) {
+                    Some(PdfRect::new_from_values(bottom, left, top, right))
+                } else {
+                    None
+                }
+            })
+            .reduce(|a, b| {
+                PdfRect::new_from_values(
+                    a.bottom.value.min(b.bottom.value),
+                    a.left.value.min(b.left.value),
+                    a.top.value.max(b.top.value),
+                    a.right.value.max(b.right.value),
+                )
+            })
+    }
+
     #[inline]
     fn is_cloneable_impl(&self) -> bool {
         false
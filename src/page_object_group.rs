@@ -498,6 +498,8 @@ impl<'a> PdfPageGroupObject<'a> {
             self.bindings,
         )?;
 
+        destination.invalidate_page_cache();
+
         Ok(())
     }
 
@@ -596,6 +598,10 @@ impl<'a> PdfPageGroupObject<'a> {
     ///
     /// An overview of PDF transformation matrices can be found in the PDF Reference Manual
     /// version 1.7 on page 204; a detailed description can be founded in section 4.2.3 on page 207.
+    ///
+    /// If the containing [PdfPage] has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered once on the page after every object in the group has been transformed.
     #[inline]
     pub fn transform(
         &mut self,
@@ -715,6 +721,17 @@ impl<'a> PdfPageGroupObject<'a> {
         self.apply_to_each(|object| object.set_line_cap(line_cap))
     }
 
+    /// Sets the dash pattern used when painting stroked lines in every [PdfPageObject]
+    /// in this group. An empty dash array denotes a solid line.
+    #[inline]
+    pub fn set_dash_pattern(
+        &mut self,
+        dash_array: &[PdfPoints],
+        phase: PdfPoints,
+    ) -> Result<(), PdfiumError> {
+        self.apply_to_each(|object| object.set_dash_pattern(dash_array, phase))
+    }
+
     /// Sets the method used to determine which sub-paths of any path in a [PdfPageObject]
     /// should be filled, and whether or not any path in a [PdfPageObject] should be stroked,
     /// for every [PdfPageObject] in this group.
@@ -737,6 +754,11 @@ impl<'a> PdfPageGroupObject<'a> {
     }
 
     /// Applies the given closure to each [PdfPageObject] in this group.
+    ///
+    /// If the containing [PdfPage] has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page once, after the closure has been applied to every object
+    /// in the group, rather than once per object.
     #[inline]
     pub(crate) fn apply_to_each<F, T>(&mut self, f: F) -> Result<(), PdfiumError>
     where
@@ -752,7 +774,15 @@ impl<'a> PdfPageGroupObject<'a> {
 
         match error {
             Some(err) => Err(err),
-            None => Ok(()),
+            None => {
+                if self.do_regenerate_page_content_after_each_change
+                    && !self.object_handles.is_empty()
+                {
+                    PdfPage::regenerate_content_immut_for_handle(self.page_handle, self.bindings)?;
+                }
+
+                Ok(())
+            }
         }
     }
 
@@ -927,4 +957,61 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_group_apply_regenerates_content() -> Result<(), PdfiumError> {
+        // Translates a group of objects and saves the document without regenerating the page's
+        // content explicitly. The saved content reflects the translation only if the group
+        // regenerated the page's content after applying the change to all its objects.
+
+        let pdfium = test_bind_to_pdfium();
+
+        for strategy in [
+            PdfPageContentRegenerationStrategy::AutomaticOnEveryChange,
+            PdfPageContentRegenerationStrategy::Manual,
+        ] {
+            let document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+                None,
+                None,
+                Some(PdfColor::SOLID_RED),
+            )?;
+
+            page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(200.0, 200.0, 300.0, 300.0),
+                None,
+                None,
+                Some(PdfColor::SOLID_BLUE),
+            )?;
+
+            page.regenerate_content()?;
+
+            page.set_content_regeneration_strategy(strategy);
+
+            let mut group = PdfPageGroupObject::new(&page, |_| true)?;
+
+            group.translate(PdfPoints::new(150.0), PdfPoints::new(200.0))?;
+
+            let saved = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+            let saved_page = saved.pages().get(0)?;
+
+            let bounds = saved_page.objects().create_group(|_| true)?.bounds()?;
+
+            let expected_left = match strategy {
+                PdfPageContentRegenerationStrategy::AutomaticOnEveryChange => 250.0,
+                _ => 100.0,
+            };
+
+            assert_eq!(bounds.left.value, expected_left);
+        }
+
+        Ok(())
+    }
 }
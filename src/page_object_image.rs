@@ -16,20 +16,17 @@ use crate::utils::mem::create_byte_buffer;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_int, c_void};
 
-#[cfg(feature = "image")]
-use crate::bindgen::FPDF_BITMAP;
-
 #[cfg(feature = "image")]
 use crate::bitmap::{PdfBitmapFormat, Pixels};
 
 #[cfg(feature = "image")]
-use crate::page::PdfPoints;
+use crate::page::{PdfPoints, PdfRect};
 
 #[cfg(feature = "image")]
 use crate::page_object::PdfPageObjectCommon;
 
 #[cfg(feature = "image")]
-use crate::utils::pixels::{bgr_to_rgba, bgra_to_rgba, rgba_to_bgra};
+use crate::utils::pixels::{bgr_to_rgba, bgra_to_rgba, bgrx_to_rgba, gray_to_rgba, rgba_to_bgra};
 
 #[cfg(feature = "image")]
 use std::convert::TryInto;
@@ -201,22 +198,39 @@ impl<'a> PdfPageImageObject<'a> {
     /// Returns a new [PdfBitmap] created from the bitmap buffer backing
     /// this [PdfPageImageObject], ignoring any image filters, image mask, or object
     /// transforms applied to this page object.
+    ///
+    /// Pdfium decodes the image's pixel data into one of the [PdfBitmapFormat] pixel formats,
+    /// converting indexed, ICC-based, and other color spaces as necessary; the original
+    /// color space of the image can be retrieved by calling [PdfPageImageObject::color_space()].
+    /// Use [PdfBitmap::format()] and [PdfBitmap::stride()] to interpret the returned
+    /// bitmap's buffer.
     pub fn get_raw_bitmap(&self) -> Result<PdfBitmap, PdfiumError> {
-        Ok(PdfBitmap::from_pdfium(
-            self.bindings.FPDFImageObj_GetBitmap(self.object_handle),
-            self.bindings,
-        ))
+        let handle = self.bindings.FPDFImageObj_GetBitmap(self.object_handle);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfBitmap::from_pdfium(handle, self.bindings))
+        }
     }
 
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing
     /// this [PdfPageImageObject], ignoring any image filters, image mask, or object
     /// transforms applied to this page object.
     ///
+    /// The returned image is always normalized to 8-bit RGBA, regardless of the pixel format
+    /// of the bitmap decoded by Pdfium. Images without an alpha channel are returned
+    /// fully opaque.
+    ///
     /// This function is only available when this crate's `image` feature is enabled.
     #[cfg(feature = "image")]
     #[inline]
     pub fn get_raw_image(&self) -> Result<DynamicImage, PdfiumError> {
-        self.get_image_from_bitmap_handle(*self.get_raw_bitmap()?.handle())
+        self.get_image_from_bitmap(&self.get_raw_bitmap()?)
     }
 
     /// Returns a new [PdfBitmap] created from the bitmap buffer backing
@@ -476,58 +490,49 @@ impl<'a> PdfPageImageObject<'a> {
         height: Pixels,
     ) -> Result<DynamicImage, PdfiumError> {
         self.get_processed_bitmap_with_size(document, width, height)
-            .and_then(|bitmap| self.get_image_from_bitmap_handle(*bitmap.handle()))
+            .and_then(|bitmap| self.get_image_from_bitmap(&bitmap))
     }
 
     #[cfg(feature = "image")]
-    pub(crate) fn get_image_from_bitmap_handle(
+    pub(crate) fn get_image_from_bitmap(
         &self,
-        bitmap: FPDF_BITMAP,
+        bitmap: &PdfBitmap,
     ) -> Result<DynamicImage, PdfiumError> {
-        if bitmap.is_null() {
-            if let Some(error) = self.bindings.get_pdfium_last_error() {
-                Err(PdfiumError::PdfiumLibraryInternalError(error))
-            } else {
-                // This would be an unusual situation; a null handle indicating failure,
-                // yet Pdfium's error code indicates success.
+        let width = bitmap.width() as usize;
 
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
-                ))
-            }
-        } else {
-            let width = self.bindings.FPDFBitmap_GetWidth(bitmap);
+        let height = bitmap.height() as usize;
 
-            let height = self.bindings.FPDFBitmap_GetHeight(bitmap);
+        let stride = bitmap.stride();
 
-            let buffer_length = self.bindings.FPDFBitmap_GetStride(bitmap) * height;
+        let format = bitmap.format()?;
 
-            let buffer_start = self.bindings.FPDFBitmap_GetBuffer(bitmap);
+        let bytes_per_pixel = match format {
+            PdfBitmapFormat::Gray => 1,
+            PdfBitmapFormat::BGR => 3,
+            PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRA => 4,
+        };
 
-            let format =
-                PdfBitmapFormat::from_pdfium(self.bindings.FPDFBitmap_GetFormat(bitmap) as u32)?;
+        let row_length = width * bytes_per_pixel;
 
-            let buffer = unsafe {
-                std::slice::from_raw_parts(buffer_start as *const u8, buffer_length as usize)
-            };
+        let pixels = bitmap
+            .as_bytes()
+            .chunks(stride)
+            .take(height)
+            .flat_map(|row| {
+                let row = &row[..row_length];
 
-            let result = match format {
-                PdfBitmapFormat::BGRA | PdfBitmapFormat::BRGx => {
-                    RgbaImage::from_raw(width as u32, height as u32, bgra_to_rgba(buffer))
-                        .map(DynamicImage::ImageRgba8)
-                }
-                PdfBitmapFormat::BGR => {
-                    RgbaImage::from_raw(width as u32, height as u32, bgr_to_rgba(buffer))
-                        .map(DynamicImage::ImageRgba8)
+                match format {
+                    PdfBitmapFormat::Gray => gray_to_rgba(row),
+                    PdfBitmapFormat::BGR => bgr_to_rgba(row),
+                    PdfBitmapFormat::BRGx => bgrx_to_rgba(row),
+                    PdfBitmapFormat::BGRA => bgra_to_rgba(row),
                 }
-                _ => None,
-            }
-            .ok_or(PdfiumError::ImageError);
-
-            self.bindings.FPDFBitmap_Destroy(bitmap);
+            })
+            .collect::<Vec<_>>();
 
-            result
-        }
+        RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .map(DynamicImage::ImageRgba8)
+            .ok_or(PdfiumError::ImageError)
     }
 
     /// Return the expected pixel width and height of the processed image from Pdfium's metadata.
@@ -641,6 +646,16 @@ impl<'a> PdfPageImageObject<'a> {
         }
     }
 
+    /// Returns the width and height, in pixels, of the image assigned to this [PdfPageImageObject].
+    ///
+    /// The dimensions are read from the image's metadata; the image itself is not decoded, so this
+    /// is a cheap way of checking the size of an image before deciding whether or not to extract it.
+    /// An error will be returned if either dimension exceeds the maximum size of a [Pixels] value.
+    #[inline]
+    pub fn pixel_size(&self) -> Result<(Pixels, Pixels), PdfiumError> {
+        self.get_current_width_and_height_from_metadata()
+    }
+
     /// Returns the horizontal dots per inch resolution of the image assigned to this
     /// [PdfPageImageObject], based on the intrinsic resolution of the assigned image
     /// and the dimensions of this object.
@@ -725,6 +740,14 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageImageObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+        // An image is always drawn into the unit square, scaled and positioned
+        // by its transformation matrix.
+
+        Some(PdfRect::new_from_values(0.0, 0.0, 1.0, 1.0))
+    }
+
     #[inline]
     fn is_cloneable_impl(&self) -> bool {
         // Image filters cannot be cloned.
@@ -945,6 +945,26 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPagePathObject<'a> {
         self.bindings
     }
 
+    fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+        // Segment points, including Bézier control points, are reported by Pdfium
+        // in the path's own coordinate space.
+
+        let segments = self.segments();
+
+        let mut points = segments.iter().map(|segment| segment.point());
+
+        let (x, y) = points.next()?;
+
+        Some(points.fold(PdfRect::new(y, x, y, x), |bounds, (x, y)| {
+            PdfRect::new_from_values(
+                bounds.bottom.value.min(y.value),
+                bounds.left.value.min(x.value),
+                bounds.top.value.max(y.value),
+                bounds.right.value.max(x.value),
+            )
+        }))
+    }
+
     #[inline]
     fn is_cloneable_impl(&self) -> bool {
         // The path object can only be cloned if it contains no Bézier path segments.
@@ -970,6 +990,14 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPagePathObject<'a> {
         clone.set_stroke_width(self.stroke_width()?)?;
         clone.set_line_join(self.line_join()?)?;
         clone.set_line_cap(self.line_cap()?)?;
+        clone.set_dash_pattern_unchecked_impl(
+            &self
+                .dash_array()?
+                .iter()
+                .map(|length| length.value)
+                .collect::<Vec<_>>(),
+            self.dash_phase()?.value,
+        )?;
 
         for segment in self.segments().iter() {
             if segment.segment_type() == PdfPathSegmentType::Unknown {
@@ -7,7 +7,9 @@ pub(crate) mod internal {
     // Instead of making the PdfPageObjectPrivate trait private, we leave it public but place it
     // inside this pub(crate) module in order to prevent it from being visible outside the crate.
 
-    use crate::bindgen::{FPDF_ANNOTATION, FPDF_PAGE, FPDF_PAGEOBJECT, FS_MATRIX, FS_RECTF};
+    use crate::bindgen::{
+        size_t, FPDF_ANNOTATION, FPDF_BOOL, FPDF_PAGE, FPDF_PAGEOBJECT, FS_MATRIX, FS_RECTF,
+    };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::document::PdfDocument;
     use crate::error::{PdfiumError, PdfiumInternalError};
@@ -16,6 +18,9 @@ pub(crate) mod internal {
     use crate::page_object::{PdfPageObject, PdfPageObjectCommon};
     use crate::page_objects::PdfPageObjects;
     use crate::transform::PdfMatrix;
+    use crate::utils::mem::create_byte_buffer;
+    use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+    use std::os::raw::{c_ulong, c_void};
 
     /// Internal crate-specific functionality common to all [PdfPageObject] objects.
     pub(crate) trait PdfPageObjectPrivate<'a>: PdfPageObjectCommon<'a> {
@@ -217,6 +222,111 @@ pub(crate) mod internal {
             )
         }
 
+        /// Returns the bounds of this [PdfPageObject] in its own coordinate space, before its
+        /// transformation matrix is applied, or `None` if they cannot be determined for
+        /// this type of object.
+        #[inline]
+        fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+            None
+        }
+
+        /// Internal implementation of [PdfPageObjectCommon::rotated_bounds()].
+        fn rotated_bounds_impl(&self) -> Result<[(PdfPoints, PdfPoints); 4], PdfiumError> {
+            match self.untransformed_bounds_impl() {
+                Some(bounds) => {
+                    let matrix = self.matrix()?;
+
+                    Ok([
+                        matrix.apply_to_point(bounds.left, bounds.bottom),
+                        matrix.apply_to_point(bounds.right, bounds.bottom),
+                        matrix.apply_to_point(bounds.right, bounds.top),
+                        matrix.apply_to_point(bounds.left, bounds.top),
+                    ])
+                }
+                None => {
+                    // Fall back to the corners of the axis-aligned bounds.
+
+                    let bounds = self.bounds_impl()?;
+
+                    Ok([
+                        (bounds.left, bounds.bottom),
+                        (bounds.right, bounds.bottom),
+                        (bounds.right, bounds.top),
+                        (bounds.left, bounds.top),
+                    ])
+                }
+            }
+        }
+
+        /// Internal implementation of [PdfPageObjectCommon::optional_content_group()].
+        fn optional_content_group_impl(&self) -> Option<String> {
+            // An object belonging to an optional content group is enclosed in a marked content
+            // sequence tagged /OC. Pdfium resolves the sequence's property list from the page's
+            // resources, so for a single optional content group the mark's parameters are the
+            // entries of the group's own dictionary, including its /Name.
+
+            let bindings = self.bindings();
+
+            let object_handle = *self.get_object_handle();
+
+            (0..bindings.FPDFPageObj_CountMarks(object_handle)).find_map(|index| {
+                let mark = bindings.FPDFPageObj_GetMark(object_handle, index as c_ulong);
+
+                if mark.is_null() {
+                    return None;
+                }
+
+                let name = self.get_mark_string(&|buffer, buffer_length, out_buffer_length| {
+                    bindings.FPDFPageObjMark_GetName(mark, buffer, buffer_length, out_buffer_length)
+                })?;
+
+                if name != "OC" {
+                    return None;
+                }
+
+                self.get_mark_string(&|buffer, buffer_length, out_buffer_length| {
+                    bindings.FPDFPageObjMark_GetParamStringValue(
+                        mark,
+                        "Name",
+                        buffer,
+                        buffer_length,
+                        out_buffer_length,
+                    )
+                })
+            })
+        }
+
+        /// Retrieves a UTF-16LE string from one of Pdfium's page object mark functions
+        /// using the given two-step retrieval function.
+        fn get_mark_string(
+            &self,
+            f: &dyn Fn(*mut c_void, c_ulong, *mut c_ulong) -> FPDF_BOOL,
+        ) -> Option<String> {
+            let mut buffer_length: c_ulong = 0;
+
+            if !self
+                .bindings()
+                .is_true(f(std::ptr::null_mut(), 0, &mut buffer_length))
+                || buffer_length == 0
+            {
+                return None;
+            }
+
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let mut out_buffer_length: c_ulong = 0;
+
+            if self.bindings().is_true(f(
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+                &mut out_buffer_length,
+            )) {
+                get_string_from_pdfium_utf16le_bytes(buffer)
+            } else {
+                None
+            }
+        }
+
         /// Internal implementation of [PdfPageObjectCommon::transform()].
         #[inline]
         fn transform_impl(
@@ -237,6 +347,29 @@ pub(crate) mod internal {
             }
         }
 
+        /// Sets the dash pattern of this page object without validating it. Dash patterns read
+        /// from an existing document need not pass the validation applied by
+        /// [PdfPageObjectCommon::set_dash_pattern()], so they are copied using this function.
+        fn set_dash_pattern_unchecked_impl(
+            &mut self,
+            dash_array: &[f32],
+            phase: f32,
+        ) -> Result<(), PdfiumError> {
+            if self
+                .bindings()
+                .is_true(self.bindings().FPDFPageObj_SetDashArray(
+                    *self.get_object_handle(),
+                    dash_array.as_ptr(),
+                    dash_array.len() as size_t,
+                    phase,
+                ))
+            {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            }
+        }
+
         /// Returns the current raw transformation matrix for this page object.
         fn matrix(&self) -> Result<PdfMatrix, PdfiumError> {
             let mut matrix = FS_MATRIX {
@@ -341,6 +474,7 @@ pub(crate) mod internal {
 
 #[cfg(test)]
 pub mod tests {
+    use crate::page_object_private::internal::PdfPageObjectPrivate;
     use crate::prelude::*;
     use crate::utils::test::test_bind_to_pdfium;
 
@@ -490,4 +624,117 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_object_rotated_bounds() -> Result<(), PdfiumError> {
+        // Tests to make sure the rotated bounds of an object follow its rotation, and that
+        // retrieving them leaves the object's transformation matrix unchanged.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = PdfPagePathObject::new_rect(
+            &document,
+            PdfRect::new_from_values(0.0, 0.0, 50.0, 100.0),
+            None,
+            None,
+            Some(PdfColor::SOLID_RED),
+        )?;
+
+        object.rotate_counter_clockwise_degrees(90.0)?;
+        object.translate(PdfPoints::new(200.0), PdfPoints::new(200.0))?;
+
+        let object = page.objects_mut().add_path_object(object)?;
+
+        let matrix = object.get_matrix()?;
+
+        let corners = object.rotated_bounds()?;
+
+        let expected = [
+            (200.0, 200.0),
+            (200.0, 300.0),
+            (150.0, 300.0),
+            (150.0, 200.0),
+        ];
+
+        for ((x, y), (expected_x, expected_y)) in corners.iter().zip(expected.iter()) {
+            assert!((x.value - expected_x).abs() < 0.01);
+            assert!((y.value - expected_y).abs() < 0.01);
+        }
+
+        assert_eq!(object.get_matrix()?, matrix);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_object_dash_pattern_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut object = PdfPagePathObject::new_rect(
+            &document,
+            PdfRect::new_from_values(100.0, 100.0, 400.0, 400.0),
+            Some(PdfColor::SOLID_RED),
+            Some(PdfPoints::new(1.0)),
+            None,
+        )?;
+
+        object.set_dash_pattern(
+            &[PdfPoints::new(3.0), PdfPoints::new(2.0)],
+            PdfPoints::new(1.0),
+        )?;
+
+        assert_eq!(
+            object.dash_array()?,
+            vec![PdfPoints::new(3.0), PdfPoints::new(2.0)]
+        );
+        assert_eq!(object.dash_phase()?, PdfPoints::new(1.0));
+
+        object.set_dash_pattern(&[], PdfPoints::ZERO)?;
+
+        assert!(object.dash_array()?.is_empty());
+        assert_eq!(object.dash_phase()?, PdfPoints::ZERO);
+
+        assert!(object
+            .set_dash_pattern(&[PdfPoints::new(-1.0)], PdfPoints::ZERO)
+            .is_err());
+        assert!(object
+            .set_dash_pattern(&[PdfPoints::ZERO, PdfPoints::ZERO], PdfPoints::ZERO)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_object_copy_retains_unvalidated_dash_pattern() -> Result<(), PdfiumError> {
+        // An all-zero dash array is rejected by set_dash_pattern(), but may still be present
+        // in an existing document. Copying such an object must not fail.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut object = PdfPagePathObject::new_rect(
+            &document,
+            PdfRect::new_from_values(100.0, 100.0, 400.0, 400.0),
+            Some(PdfColor::SOLID_RED),
+            Some(PdfPoints::new(1.0)),
+            None,
+        )?;
+
+        object.set_dash_pattern_unchecked_impl(&[0.0, 0.0], 0.0)?;
+
+        let copy = object.try_copy(&document)?;
+
+        assert_eq!(copy.dash_array()?, vec![PdfPoints::ZERO, PdfPoints::ZERO]);
+
+        Ok(())
+    }
 }
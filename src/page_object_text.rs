@@ -16,7 +16,7 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::font::PdfFont;
-use crate::page::PdfPoints;
+use crate::page::{PdfPoints, PdfRect};
 use crate::page_object::{PdfPageObject, PdfPageObjectCommon};
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_text::PdfPageText;
@@ -267,6 +267,48 @@ impl<'a> PdfPageTextObject<'a> {
         )
     }
 
+    /// Returns the raw weight of the font used to render the text contained within this
+    /// [PdfPageTextObject], typically a value between 100 and 900, or `None` if Pdfium could
+    /// not determine the font weight.
+    ///
+    /// Pdfium may not reliably return the correct value of this property for built-in fonts.
+    pub fn font_weight(&self) -> Option<u32> {
+        let weight = self
+            .bindings()
+            .FPDFFont_GetWeight(self.bindings().FPDFTextObj_GetFont(self.object_handle));
+
+        if weight < 0 {
+            None
+        } else {
+            Some(weight as u32)
+        }
+    }
+
+    /// Returns `true` if the text contained within this [PdfPageTextObject] is rendered
+    /// in a bold font.
+    ///
+    /// A font is considered bold if its weight is 600 or greater, or if its font descriptor
+    /// flags request that bold glyphs be thickened. This is more reliable than inspecting the
+    /// font name for substrings such as "Bold".
+    pub fn is_bold(&self) -> bool {
+        self.font_weight()
+            .map(|weight| weight >= 600)
+            .unwrap_or(false)
+            || self.font().is_bold_reenforced()
+    }
+
+    /// Returns `true` if the text contained within this [PdfPageTextObject] is rendered
+    /// in an italic or oblique font.
+    ///
+    /// A font is considered italic if its font descriptor flags mark it as italic, or if
+    /// it has a non-zero italic angle. This is more reliable than inspecting the font name
+    /// for substrings such as "Italic" or "Oblique".
+    pub fn is_italic(&self) -> bool {
+        let font = self.font();
+
+        font.is_italic() || font.italic_angle().map(|angle| angle != 0).unwrap_or(false)
+    }
+
     /// Returns the text contained within this [PdfPageTextObject].
     ///
     /// Text retrieval in Pdfium is handled by the [PdfPageText] object owned by the `PdfPage`
@@ -311,6 +353,8 @@ impl<'a> PdfPageTextObject<'a> {
                 if buffer_length == 0 {
                     // There is no text.
 
+                    self.bindings.FPDFText_ClosePage(text_handle);
+
                     return String::new();
                 }
 
@@ -463,6 +507,38 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageTextObject<'a> {
         self.bindings
     }
 
+    fn untransformed_bounds_impl(&self) -> Option<PdfRect> {
+        // Pdfium does not report the bounds of a text object in text space, so we approximate
+        // them from the axis-aligned bounds. Assuming the text starts at the origin of its
+        // text matrix, the width follows from the position of the centre of the bounds in text
+        // space, and the height from the extent of the axis-aligned bounds.
+
+        let matrix = self.matrix().ok()?;
+
+        let bounds = self.bounds_impl().ok()?;
+
+        let (centre_x, centre_y) = matrix.invert().ok()?.apply_to_point(
+            (bounds.left + bounds.right) / 2.0,
+            (bounds.bottom + bounds.top) / 2.0,
+        );
+
+        let width = (centre_x.value * 2.0).max(0.0);
+
+        let height = if matrix.d.abs() >= matrix.c.abs() {
+            (bounds.height().value - matrix.b.abs() * width) / matrix.d.abs()
+        } else {
+            (bounds.width().value - matrix.a.abs() * width) / matrix.c.abs()
+        }
+        .max(0.0);
+
+        Some(PdfRect::new_from_values(
+            centre_y.value - height / 2.0,
+            0.0,
+            centre_y.value + height / 2.0,
+            width,
+        ))
+    }
+
     #[inline]
     fn is_cloneable_impl(&self) -> bool {
         true
@@ -3,7 +3,9 @@
 
 use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::form_xobject::PdfFormXObject;
 use crate::page_object::PdfPageObject;
 use crate::page_object_group::PdfPageGroupObject;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
@@ -11,6 +13,7 @@ use crate::page_objects_common::{
     PdfPageObjectIndex, PdfPageObjectsCommon, PdfPageObjectsIterator,
 };
 use crate::page_objects_private::internal::PdfPageObjectsPrivate;
+use crate::pages::PdfPageIndex;
 use std::os::raw::c_int;
 
 /// The page objects contained within a single `PdfPage`.
@@ -21,7 +24,9 @@ use std::os::raw::c_int;
 /// Note that Pdfium does not support or recognize all PDF page object types. For instance,
 /// Pdfium does not currently support or recognize the External Object ("XObject") page object type
 /// supported by Adobe Acrobat and Foxit's commercial PDF SDK. In these cases, Pdfium will return
-/// `PdfPageObjectType::Unsupported`.
+/// `PdfPageObjectType::Unsupported`. Form XObjects are the exception: they can be created from
+/// existing pages using the [PdfPageObjects::create_form_xobject_from_page()] function and are
+/// placed on a page as `PdfPageObjectType::FormFragment` objects.
 pub struct PdfPageObjects<'a> {
     page_handle: FPDF_PAGE,
     document_handle: FPDF_DOCUMENT,
@@ -88,6 +93,38 @@ impl<'a> PdfPageObjects<'a> {
             self.do_regenerate_page_content_after_each_change,
         )
     }
+
+    /// Creates a new reusable [PdfFormXObject] from the content of the page at the given index
+    /// in the given source document. The source document can be the document containing this
+    /// [PdfPageObjects] collection, or any other open document.
+    ///
+    /// The content of the source page is imported into the document containing this
+    /// [PdfPageObjects] collection once, as a single shared form XObject. Use the
+    /// [PdfFormXObject::create_object()] function to create as many placements of the
+    /// form XObject as required, and add them to any page in the same document; each placement
+    /// can be given its own transformation matrix, and all placements reference the same
+    /// shared resource when page content is regenerated.
+    pub fn create_form_xobject_from_page(
+        &self,
+        source: &PdfDocument,
+        page_index: PdfPageIndex,
+    ) -> Result<PdfFormXObject<'a>, PdfiumError> {
+        let handle = self.bindings.FPDF_NewXObjectFromPage(
+            self.document_handle,
+            *source.handle(),
+            page_index as c_int,
+        );
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfFormXObject::from_pdfium(handle, self.bindings))
+        }
+    }
 }
 
 impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
@@ -8,6 +8,7 @@ use crate::page::{PdfPoints, PdfRect};
 use crate::page_object::{PdfPageObject, PdfPageObjectCommon};
 use crate::page_object_image::PdfPageImageObject;
 use crate::page_object_path::PdfPagePathObject;
+use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_object_text::PdfPageTextObject;
 use crate::page_objects_private::internal::PdfPageObjectsPrivate;
 use std::ops::{Range, RangeInclusive};
@@ -19,6 +20,11 @@ pub type PdfPageObjectIndex = usize;
 
 /// Functionality common to all containers of multiple [PdfPageObject] objects.
 /// Both pages and annotations can contain page objects.
+///
+/// Page objects in a collection are indexed in the order in which they appear in the
+/// content stream, which is also the order in which they are painted. The object at index 0
+/// is painted first, and so is at the bottom of the z-order; the object at index `len() - 1`
+/// is painted last, and so is at the top of the z-order.
 pub trait PdfPageObjectsCommon<'a> {
     /// Returns the total number of page objects in the collection.
     fn len(&self) -> PdfPageObjectIndex;
@@ -46,6 +52,8 @@ pub trait PdfPageObjectsCommon<'a> {
     }
 
     /// Returns a single [PdfPageObject] from this page objects collection.
+    ///
+    /// The object at index 0 is painted first, and so is at the bottom of the z-order.
     fn get(&self, index: PdfPageObjectIndex) -> Result<PdfPageObject<'a>, PdfiumError>;
 
     /// Returns the first [PdfPageObject] in this page objects collection.
@@ -68,7 +76,20 @@ pub trait PdfPageObjectsCommon<'a> {
         }
     }
 
-    /// Returns an iterator over all the [PdfPageObject] objects in this page objects collection.
+    /// Returns the index of the given [PdfPageObject] in this page objects collection,
+    /// if the object is a member of this collection.
+    ///
+    /// The object at index 0 is painted first, and so is at the bottom of the z-order.
+    fn index_of(&self, object: &PdfPageObject) -> Option<PdfPageObjectIndex> {
+        self.as_range().find(|index| {
+            self.get(*index)
+                .map(|candidate| candidate.get_object_handle() == object.get_object_handle())
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns an iterator over all the [PdfPageObject] objects in this page objects collection,
+    /// in the order in which they are painted, from the bottom of the z-order to the top.
     fn iter(&'a self) -> PdfPageObjectsIterator<'a>;
 
     /// Adds the given [PdfPageObject] to this page objects collection. The object's
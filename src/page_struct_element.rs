@@ -0,0 +1,234 @@
+//! Defines the [PdfStructElement] struct, exposing functionality related to a single
+//! element in the logical structure tree of a tagged `PdfDocument`.
+
+use crate::bindgen::{FPDF_PAGE, FPDF_STRUCTELEMENT};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::fmt::{Debug, Formatter};
+use std::os::raw::{c_int, c_ulong, c_void};
+
+/// A single element in the logical structure tree of a tagged `PdfDocument`, such as a
+/// heading, paragraph, or figure.
+///
+/// Structure elements are linked to the content of a `PdfPage` by marked-content identifiers.
+/// Use the `PdfPage::struct_element_for_object()` function to retrieve the structure element
+/// that owns a given page object.
+#[derive(Clone)]
+pub struct PdfStructElement<'a> {
+    element_type: Option<String>,
+    id: Option<String>,
+    title: Option<String>,
+    alt_text: Option<String>,
+    language: Option<String>,
+    marked_content_id: i32,
+    page_handle: FPDF_PAGE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    /// Searches the structure tree of the page with the given handle for the element
+    /// that directly owns the given marked-content identifier.
+    pub(crate) fn find_for_marked_content_id(
+        page_handle: FPDF_PAGE,
+        marked_content_id: i32,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Option<Self> {
+        Self::with_element_for_marked_content_id(
+            page_handle,
+            marked_content_id,
+            bindings,
+            |element| Self::from_pdfium(element, page_handle, marked_content_id, bindings),
+        )
+    }
+
+    /// Searches the structure tree of the page with the given handle for the element
+    /// that directly owns the given marked-content identifier, returning the result of
+    /// calling the given function on the element's handle. Pdfium only keeps structure
+    /// element handles alive while the page's structure tree is open, so the handle
+    /// must not escape the given function.
+    fn with_element_for_marked_content_id<T>(
+        page_handle: FPDF_PAGE,
+        marked_content_id: i32,
+        bindings: &dyn PdfiumLibraryBindings,
+        f: impl FnOnce(FPDF_STRUCTELEMENT) -> T,
+    ) -> Option<T> {
+        let tree = bindings.FPDF_StructTree_GetForPage(page_handle);
+
+        if tree.is_null() {
+            return None;
+        }
+
+        let element = (0..bindings.FPDF_StructTree_CountChildren(tree)).find_map(|index| {
+            Self::find_in_element(
+                bindings.FPDF_StructTree_GetChildAtIndex(tree, index),
+                marked_content_id,
+                bindings,
+            )
+        });
+
+        let result = element.map(f);
+
+        bindings.FPDF_StructTree_Close(tree);
+
+        result
+    }
+
+    /// Recursively searches the given structure element and its children for the element
+    /// that directly owns the given marked-content identifier.
+    fn find_in_element(
+        element: FPDF_STRUCTELEMENT,
+        marked_content_id: i32,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Option<FPDF_STRUCTELEMENT> {
+        if element.is_null() {
+            return None;
+        }
+
+        if bindings.FPDF_StructElement_GetMarkedContentID(element) == marked_content_id {
+            return Some(element);
+        }
+
+        (0..bindings.FPDF_StructElement_CountChildren(element)).find_map(|index| {
+            Self::find_in_element(
+                bindings.FPDF_StructElement_GetChildAtIndex(element, index as c_int),
+                marked_content_id,
+                bindings,
+            )
+        })
+    }
+
+    #[inline]
+    fn from_pdfium(
+        element: FPDF_STRUCTELEMENT,
+        page_handle: FPDF_PAGE,
+        marked_content_id: i32,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement {
+            element_type: Self::get_string(|buffer, buffer_length| {
+                bindings.FPDF_StructElement_GetType(element, buffer, buffer_length)
+            }),
+            id: Self::get_string(|buffer, buffer_length| {
+                bindings.FPDF_StructElement_GetID(element, buffer, buffer_length)
+            }),
+            title: Self::get_string(|buffer, buffer_length| {
+                bindings.FPDF_StructElement_GetTitle(element, buffer, buffer_length)
+            }),
+            alt_text: Self::get_string(|buffer, buffer_length| {
+                bindings.FPDF_StructElement_GetAltText(element, buffer, buffer_length)
+            }),
+            language: Self::get_string(|buffer, buffer_length| {
+                bindings.FPDF_StructElement_GetLang(element, buffer, buffer_length)
+            }),
+            marked_content_id,
+            page_handle,
+            bindings,
+        }
+    }
+
+    /// Retrieves a UTF-16LE string from Pdfium using the given two-step retrieval function.
+    fn get_string(f: impl Fn(*mut c_void, c_ulong) -> c_ulong) -> Option<String> {
+        let buffer_length = f(std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = f(buffer.as_mut_ptr() as *mut c_void, buffer_length);
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+
+    /// Returns the structure type of this [PdfStructElement], for instance `H1` or `P`.
+    #[inline]
+    pub fn element_type(&self) -> Option<&str> {
+        self.element_type.as_deref()
+    }
+
+    /// Returns the unique identifier of this [PdfStructElement], if any. Other elements
+    /// and annotations may refer to this element by its identifier.
+    #[inline]
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the title of this [PdfStructElement], if any.
+    #[inline]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Returns the alternate text description of this [PdfStructElement], if any.
+    #[inline]
+    pub fn alt_text(&self) -> Option<&str> {
+        self.alt_text.as_deref()
+    }
+
+    /// Returns the natural language of this [PdfStructElement], if specified.
+    #[inline]
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Returns the marked-content identifier linking this [PdfStructElement] to page content.
+    #[inline]
+    pub fn marked_content_id(&self) -> i32 {
+        self.marked_content_id
+    }
+
+    /// Returns the value of the attribute with the given name in this [PdfStructElement]'s
+    /// attribute objects, if any, for instance `Placement` or `Scope`.
+    ///
+    /// Only attributes whose values are strings or names are returned; attributes with
+    /// numeric, array, or dictionary values are not available.
+    pub fn string_attribute(&self, name: &str) -> Option<String> {
+        let bindings = self.bindings;
+
+        Self::with_element_for_marked_content_id(
+            self.page_handle,
+            self.marked_content_id,
+            bindings,
+            |element| {
+                Self::get_string(|buffer, buffer_length| {
+                    bindings.FPDF_StructElement_GetStringAttribute(
+                        element,
+                        name,
+                        buffer,
+                        buffer_length,
+                    )
+                })
+            },
+        )
+        .flatten()
+    }
+}
+
+impl<'a> PartialEq for PdfStructElement<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element_type == other.element_type
+            && self.id == other.id
+            && self.title == other.title
+            && self.alt_text == other.alt_text
+            && self.language == other.language
+            && self.marked_content_id == other.marked_content_id
+            && self.page_handle == other.page_handle
+    }
+}
+
+impl<'a> Debug for PdfStructElement<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PdfStructElement")
+            .field("element_type", &self.element_type)
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("alt_text", &self.alt_text)
+            .field("language", &self.language)
+            .field("marked_content_id", &self.marked_content_id)
+            .finish()
+    }
+}
@@ -3,19 +3,23 @@
 
 use crate::bindgen::{FPDF_TEXTPAGE, FPDF_WCHAR};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::page::{PdfPage, PdfRect};
+use crate::bitmap::PdfBitmapRotation;
+use crate::page::{PdfPage, PdfPoints, PdfRect};
 use crate::page_annotation::PdfPageAnnotation;
 use crate::page_annotation::PdfPageAnnotationCommon;
 use crate::page_object::PdfPageObjectCommon;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_object_text::PdfPageTextObject;
-use crate::page_text_chars::PdfPageTextChars;
+use crate::page_text_chars::{PdfPageTextCharIndex, PdfPageTextChars};
+use crate::page_text_line::PdfPageTextLine;
+use crate::page_text_search::{PdfPageTextSearch, PdfSearchOptions};
 use crate::page_text_segments::PdfPageTextSegments;
-use crate::prelude::PdfiumError;
+use crate::prelude::{PdfiumError, PdfiumInternalError};
 use crate::utils::mem::{create_byte_buffer, create_sized_buffer};
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use bytemuck::cast_slice;
 use std::fmt::{Display, Formatter};
+use std::os::raw::c_int;
 use std::ptr::null_mut;
 
 /// The collection of Unicode characters visible in a single [PdfPage].
@@ -135,6 +139,36 @@ impl<'a> PdfPageText<'a> {
         }
     }
 
+    /// Starts a new search for the given text in the containing [PdfPage], using the given
+    /// [PdfSearchOptions]. The search begins at the character with the given index, if provided,
+    /// or at the start of the page otherwise.
+    ///
+    /// The returned [PdfPageTextSearch] retains its position between calls, so it can be used to
+    /// step forwards and backwards through successive matches.
+    pub fn search(
+        &self,
+        text: &str,
+        options: &PdfSearchOptions,
+        start_index: Option<PdfPageTextCharIndex>,
+    ) -> Result<PdfPageTextSearch<'_>, PdfiumError> {
+        let handle = self.bindings.FPDFText_FindStart_str(
+            self.handle,
+            text,
+            options.as_pdfium(),
+            start_index.unwrap_or(0) as c_int,
+        );
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageTextSearch::from_pdfium(handle, self, self.bindings))
+        }
+    }
+
     /// Returns all characters that lie within the containing [PdfPage], in the order in which
     /// they are defined in the document, concatenated into a single string.
     ///
@@ -145,6 +179,75 @@ impl<'a> PdfPageText<'a> {
         self.inside_rect(self.page.page_size())
     }
 
+    /// Returns all characters that lie within the containing [PdfPage], in the order in which
+    /// they are defined in the document, concatenated into a single string with its whitespace
+    /// normalized. Use the [PdfPageText::all()] function instead to retrieve the raw text
+    /// exactly as returned by Pdfium.
+    ///
+    /// The following normalization is applied, in order:
+    /// * Carriage return and carriage return / line feed pairs are converted to a single
+    ///   line feed (`\n`).
+    /// * Every other run of one or more whitespace characters within a line, including
+    ///   tabs and non-breaking spaces, is replaced by a single space.
+    /// * Spaces at the start and end of each line are removed.
+    /// * Runs of more than one empty line are collapsed into a single empty line.
+    /// * Line breaks at the start and end of the text are removed.
+    pub fn text_normalized(&self) -> String {
+        normalize_whitespace(self.all().as_str())
+    }
+
+    /// Returns the given number of characters in the containing [PdfPage], starting at the
+    /// given character index, concatenated into a single string. Characters are returned in the
+    /// order in which they are defined in the document.
+    ///
+    /// Only the requested range of characters is retrieved from Pdfium, so this function can be
+    /// used to stream the text of very large pages in smaller chunks rather than retrieving
+    /// all the text on the page at once.
+    ///
+    /// An error will be returned if the requested range extends beyond the last character
+    /// in the containing [PdfPage].
+    pub fn text_range(
+        &self,
+        start: PdfPageTextCharIndex,
+        count: PdfPageTextCharIndex,
+    ) -> Result<String, PdfiumError> {
+        let end = start
+            .checked_add(count)
+            .ok_or(PdfiumError::CharIndexOutOfBounds)?;
+
+        if end > self.len().max(0) as PdfPageTextCharIndex {
+            return Err(PdfiumError::CharIndexOutOfBounds);
+        }
+
+        if count == 0 {
+            return Ok(String::new());
+        }
+
+        // FPDFText_GetText() writes the requested characters into the given buffer in UTF16-LE
+        // format, followed by a null terminator, so the buffer must be sized to hold one more
+        // UTF16-LE character than the number of characters requested.
+
+        let mut buffer = create_sized_buffer::<u16>(count + 1);
+
+        let result = self.bindings.FPDFText_GetText(
+            self.handle,
+            start as c_int,
+            count as c_int,
+            buffer.as_mut_ptr(),
+        );
+
+        if result <= 0 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
+        buffer.truncate(result as usize);
+
+        Ok(
+            get_string_from_pdfium_utf16le_bytes(cast_slice(buffer.as_slice()).to_vec())
+                .unwrap_or_default(),
+        )
+    }
+
     /// Returns all characters that lie within the bounds of the given [PdfRect] in the
     /// containing [PdfPage], in the order in which they are defined in the document,
     /// concatenated into a single string.
@@ -243,6 +346,72 @@ impl<'a> PdfPageText<'a> {
         get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
     }
 
+    /// Returns the Unicode character and bounding box of every character in the containing
+    /// [PdfPage], in the order in which they are defined in the PDF file.
+    ///
+    /// Each bounding box is the loose bounding box returned by `PdfPageTextChar::loose_bounds()`,
+    /// covering the entire glyph bounds, but converted to the coordinate space of the page as
+    /// it is displayed: the page's intrinsic rotation is applied, and the origin is moved to the
+    /// bottom-left corner of the visible area of the page. The bounding boxes therefore line up
+    /// with a rendering of the page regardless of the page's rotation. To position a bounding
+    /// box over a bitmap rendered at a scale of `s` pixels per point, place its top-left corner
+    /// at `(rect.left * s, (page.height() - rect.top) * s)`.
+    ///
+    /// Characters that do not map to a Unicode character, or whose bounds cannot be
+    /// determined, are omitted.
+    pub fn char_bounds(&self) -> Result<Vec<(char, PdfRect)>, PdfiumError> {
+        let rotation = self.page.rotation()?;
+
+        let visible = self.page.boundaries().bounding()?.bounds;
+
+        // Maps a point in page space to the rotated, displayed page.
+
+        let to_display = |x: f32, y: f32| match rotation {
+            PdfBitmapRotation::None => (x - visible.left.value, y - visible.bottom.value),
+            PdfBitmapRotation::Degrees90 => (y - visible.bottom.value, visible.right.value - x),
+            PdfBitmapRotation::Degrees180 => (visible.right.value - x, visible.top.value - y),
+            PdfBitmapRotation::Degrees270 => (visible.top.value - y, x - visible.left.value),
+        };
+
+        Ok(self
+            .chars()
+            .iter()
+            .filter_map(|char| {
+                let unicode = char.unicode_char()?;
+
+                let bounds = char.loose_bounds().ok()?;
+
+                let (x1, y1) = to_display(bounds.left.value, bounds.top.value);
+
+                let (x2, y2) = to_display(bounds.right.value, bounds.bottom.value);
+
+                Some((
+                    unicode,
+                    PdfRect::new_from_values(y1.min(y2), x1.min(x2), y1.max(y2), x1.max(x2)),
+                ))
+            })
+            .collect())
+    }
+
+    /// Returns all characters in the containing [PdfPage] grouped into lines of text.
+    ///
+    /// Lines are reconstructed from the bounding boxes of the individual characters on the page:
+    /// a new line is started whenever Pdfium reports a line break, or whenever the vertical center
+    /// of a character falls outside the vertical extent of the current line. Each [PdfPageTextLine]
+    /// also carries a simple heuristic indicating whether it appears to begin a new paragraph,
+    /// based on the size of the gap between it and the preceding line.
+    ///
+    /// In complex custom layouts, the order in which characters are defined in the document
+    /// and the order in which they appear visually during rendering (and thus the order in
+    /// which they are read by a user) may not necessarily match.
+    pub fn text_lines(&self) -> Vec<PdfPageTextLine> {
+        group_chars_into_lines(
+            self.chars()
+                .iter()
+                .map(|char| (char.unicode_char(), char.loose_bounds().ok())),
+        )
+    }
+
     /// Returns all characters that lie within the bounds of the given [PdfPageAnnotation] in the
     /// containing [PdfPage], in the order in which they are defined in the document,
     /// concatenated into a single string.
@@ -272,3 +441,198 @@ impl<'a> Drop for PdfPageText<'a> {
         self.bindings.FPDFText_ClosePage(self.handle);
     }
 }
+
+/// Normalizes the whitespace in the given text, as described in [PdfPageText::text_normalized()].
+fn normalize_whitespace(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut result = String::with_capacity(text.len());
+
+    let mut pending_line_breaks = 0;
+
+    for line in text.split('\n') {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if line.is_empty() {
+            pending_line_breaks += 1;
+        } else {
+            if !result.is_empty() {
+                result.push_str(if pending_line_breaks > 0 {
+                    "\n\n"
+                } else {
+                    "\n"
+                });
+            }
+
+            result.push_str(line.as_str());
+
+            pending_line_breaks = 0;
+        }
+    }
+
+    result
+}
+
+/// Groups the given characters and their bounding boxes, in the order in which they are
+/// defined in the document, into lines of text. See [PdfPageText::text_lines()].
+fn group_chars_into_lines(
+    chars: impl Iterator<Item = (Option<char>, Option<PdfRect>)>,
+) -> Vec<PdfPageTextLine> {
+    let mut lines: Vec<(String, PdfRect)> = Vec::new();
+
+    let mut text = String::new();
+
+    let mut bounds: Option<PdfRect> = None;
+
+    for (unicode_char, char_bounds) in chars {
+        if matches!(unicode_char, Some('\r') | Some('\n')) {
+            // Pdfium has detected a line break.
+
+            if let Some(bounds) = bounds.take() {
+                lines.push((text.trim_end().to_string(), bounds));
+            }
+
+            text.clear();
+
+            continue;
+        }
+
+        if let Some(char_bounds) = char_bounds {
+            // Characters generated by Pdfium, such as spaces between words, may have
+            // an empty bounding box; these should not influence the line's bounds.
+
+            if char_bounds.width().value > 0.0 || char_bounds.height().value > 0.0 {
+                let char_center = char_bounds.bottom + char_bounds.height() / 2.0;
+
+                bounds = match bounds {
+                    Some(line_bounds)
+                        if char_center < line_bounds.bottom || char_center > line_bounds.top =>
+                    {
+                        // This character lies outside the current line.
+
+                        lines.push((text.trim_end().to_string(), line_bounds));
+                        text.clear();
+
+                        Some(char_bounds)
+                    }
+                    Some(line_bounds) => Some(PdfRect::new(
+                        PdfPoints::new(line_bounds.bottom.value.min(char_bounds.bottom.value)),
+                        PdfPoints::new(line_bounds.left.value.min(char_bounds.left.value)),
+                        PdfPoints::new(line_bounds.top.value.max(char_bounds.top.value)),
+                        PdfPoints::new(line_bounds.right.value.max(char_bounds.right.value)),
+                    )),
+                    None => Some(char_bounds),
+                };
+            }
+        }
+
+        if let Some(unicode_char) = unicode_char {
+            text.push(unicode_char);
+        }
+    }
+
+    if let Some(bounds) = bounds {
+        lines.push((text.trim_end().to_string(), bounds));
+    }
+
+    let mut result: Vec<PdfPageTextLine> = Vec::with_capacity(lines.len());
+
+    for (text, bounds) in lines.into_iter() {
+        let (gap_before, is_paragraph_start) = match result.last() {
+            Some(previous) => {
+                let previous_bounds = previous.bounds();
+
+                let gap = previous_bounds.bottom - bounds.top;
+
+                (
+                    Some(gap),
+                    gap.value < 0.0 || gap.value > previous_bounds.height().value / 2.0,
+                )
+            }
+            None => (None, true),
+        };
+
+        result.push(PdfPageTextLine::new(
+            text,
+            bounds,
+            gap_before,
+            is_paragraph_start,
+        ));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(normalize_whitespace(""), "");
+
+        assert_eq!(
+            normalize_whitespace("  Hello \t  world\u{a0}!  \r\nSecond\rline\n"),
+            "Hello world !\nSecond\nline"
+        );
+
+        assert_eq!(
+            normalize_whitespace("\r\n\r\nFirst\r\n  \r\n\r\n\r\nSecond  \r\n"),
+            "First\n\nSecond"
+        );
+    }
+
+    #[test]
+    fn test_group_chars_into_lines() {
+        let char_at = |c: char, left: f32, bottom: f32| {
+            (
+                Some(c),
+                Some(PdfRect::new_from_values(
+                    bottom,
+                    left,
+                    bottom + 12.0,
+                    left + 8.0,
+                )),
+            )
+        };
+
+        let chars = vec![
+            // First line, with a generated space that has no bounding box.
+            char_at('A', 10.0, 700.0),
+            char_at('b', 18.0, 700.0),
+            (Some(' '), None),
+            char_at('c', 34.0, 701.0),
+            // Second line, closely following the first.
+            char_at('D', 10.0, 686.0),
+            char_at('e', 18.0, 686.0),
+            // A line break reported by Pdfium.
+            (Some('\r'), None),
+            (Some('\n'), None),
+            // Third line, after a large gap.
+            char_at('F', 10.0, 650.0),
+        ];
+
+        let lines = group_chars_into_lines(chars.into_iter());
+
+        assert_eq!(
+            lines.iter().map(|line| line.text()).collect::<Vec<_>>(),
+            vec!["Ab c", "De", "F"]
+        );
+
+        let first = lines[0].bounds();
+
+        assert_eq!(first.left.value, 10.0);
+        assert_eq!(first.right.value, 42.0);
+        assert_eq!(first.bottom.value, 700.0);
+        assert_eq!(first.top.value, 713.0);
+
+        assert!(lines[0].gap_before().is_none());
+        assert!(lines[0].is_paragraph_start());
+
+        assert_eq!(lines[1].gap_before().map(|gap| gap.value), Some(2.0));
+        assert!(!lines[1].is_paragraph_start());
+
+        assert_eq!(lines[2].gap_before().map(|gap| gap.value), Some(24.0));
+        assert!(lines[2].is_paragraph_start());
+    }
+}
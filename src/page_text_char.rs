@@ -155,6 +155,26 @@ impl<'a> PdfPageTextChar<'a> {
         self.font().0.unwrap_or_default()
     }
 
+    /// Returns the name of the font applied to this character, with any font subset tag removed.
+    ///
+    /// When a document embeds only the glyphs it actually uses from a font, the name of
+    /// the embedded font is prefixed with a tag of six uppercase letters followed by a `+` sign,
+    /// for instance `ABCDEF+Helvetica`. This function returns the font name without that tag,
+    /// making it easier to group characters by font family.
+    #[inline]
+    pub fn font_name_without_subset_tag(&self) -> String {
+        let name = self.font_name();
+
+        match name.split_once('+') {
+            Some((tag, base_name))
+                if tag.len() == 6 && tag.bytes().all(|byte| byte.is_ascii_uppercase()) =>
+            {
+                base_name.to_owned()
+            }
+            _ => name,
+        }
+    }
+
     /// Returns the weight of the font applied to this character.
     ///
     /// Pdfium may not reliably return the correct value of this property for built-in fonts.
@@ -388,6 +408,10 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// To return a loose bounding box that covers the entire glyph bounds, use the
     /// [PdfPageTextChar::loose_bounds()] function.
+    ///
+    /// The bounding box is expressed in page space, without the page's intrinsic rotation
+    /// applied. To retrieve bounding boxes that line up with a rendering of a rotated page,
+    /// use the `PdfPageText::char_bounds()` function.
     pub fn tight_bounds(&self) -> Result<PdfRect, PdfiumError> {
         let mut left = 0.0;
 
@@ -422,6 +446,10 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// To return a tight bounding box that takes this character's specific shape into
     /// account, use the [PdfPageTextChar::tight_bounds()] function.
+    ///
+    /// The bounding box is expressed in page space, without the page's intrinsic rotation
+    /// applied. To retrieve bounding boxes that line up with a rendering of a rotated page,
+    /// use the `PdfPageText::char_bounds()` function.
     pub fn loose_bounds(&self) -> Result<PdfRect, PdfiumError> {
         let mut bounds = FS_RECTF {
             left: 0.0,
@@ -1,13 +1,14 @@
 //! Defines the [PdfPageTextChars] struct, a collection of all the distinct characters
 //! in a bounded rectangular region of a single `PdfPage`.
 
+use crate::bindgen::FS_RECTF;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
-use crate::page::PdfPoints;
+use crate::page::{PdfPoints, PdfRect};
 use crate::page_text::PdfPageText;
 use crate::page_text_char::PdfPageTextChar;
 use std::ops::Range;
-use std::os::raw::c_double;
+use std::os::raw::{c_double, c_int};
 
 pub type PdfPageTextCharIndex = usize;
 
@@ -82,6 +83,30 @@ impl<'a> PdfPageTextChars<'a> {
         }
     }
 
+    /// Returns the name of the font applied to the character at the given index.
+    ///
+    /// The font name is retrieved from Pdfium afresh on each call. Pdfium looks up the
+    /// character's font directly from its already-loaded text page, so each call costs the
+    /// same regardless of the number of characters and there is no need to cache the result.
+    #[inline]
+    pub fn char_font_name(&self, index: PdfPageTextCharIndex) -> Result<String, PdfiumError> {
+        self.get(index).map(|char| char.font_name())
+    }
+
+    /// Returns the name of the font applied to the character at the given index, with any
+    /// font subset tag (for instance, the `ABCDEF+` in `ABCDEF+Helvetica`) removed.
+    ///
+    /// The font name is retrieved from Pdfium afresh on each call, as for
+    /// [PdfPageTextChars::char_font_name()].
+    #[inline]
+    pub fn char_font_name_without_subset_tag(
+        &self,
+        index: PdfPageTextCharIndex,
+    ) -> Result<String, PdfiumError> {
+        self.get(index)
+            .map(|char| char.font_name_without_subset_tag())
+    }
+
     /// Returns the character at the given x and y positions on the containing `PdfPage`, if any.
     #[inline]
     pub fn get_char_at_point(&self, x: PdfPoints, y: PdfPoints) -> Option<PdfPageTextChar> {
@@ -111,6 +136,51 @@ impl<'a> PdfPageTextChars<'a> {
         }
     }
 
+    /// Returns the rectangular areas of the containing `PdfPage` occupied by the characters
+    /// in this [PdfPageTextChars] collection.
+    ///
+    /// Pdfium merges the bounds of adjacent characters on the same line that share the same
+    /// font settings, so a collection spanning several lines or text styles returns one
+    /// rectangle for each. This makes the returned rectangles suitable for highlighting
+    /// a match returned by a `PdfPageTextSearch`.
+    pub fn bounding_rects(&self) -> Result<Vec<PdfRect>, PdfiumError> {
+        let count = self
+            .bindings
+            .FPDFText_CountRects(*self.text.handle(), self.start, self.len);
+
+        (0..count)
+            .map(|index| {
+                let mut left = 0.0;
+
+                let mut bottom = 0.0;
+
+                let mut right = 0.0;
+
+                let mut top = 0.0;
+
+                let result = self.bindings.FPDFText_GetRect(
+                    *self.text.handle(),
+                    index as c_int,
+                    &mut left,
+                    &mut top,
+                    &mut right,
+                    &mut bottom,
+                );
+
+                PdfRect::from_pdfium_as_result(
+                    result,
+                    FS_RECTF {
+                        left: left as f32,
+                        top: top as f32,
+                        right: right as f32,
+                        bottom: bottom as f32,
+                    },
+                    self.bindings,
+                )
+            })
+            .collect()
+    }
+
     /// Returns an iterator over all the characters in this [PdfPageTextChars] collection.
     #[inline]
     pub fn iter(&self) -> PdfPageTextCharsIterator {
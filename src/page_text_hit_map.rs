@@ -0,0 +1,268 @@
+//! Defines the [PdfPageTextHitMap] struct, a spatial index mapping the pixels of a rendered
+//! `PdfPage` to the indices of the characters drawn at those pixels.
+
+use crate::bindgen::{FPDF_PAGE, FS_RECTF};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::bitmap::Pixels;
+use crate::page_text::PdfPageText;
+use crate::page_text_chars::PdfPageTextCharIndex;
+use crate::render_config::PdfRenderSettings;
+use std::os::raw::{c_double, c_int};
+
+/// The width and height, in pixels, of each square cell in a [PdfPageTextHitMap] grid.
+const CELL_SIZE: i32 = 32;
+
+/// The pixel bounds of a single character, as left, top, right, and bottom pixel coordinates.
+/// The right and bottom coordinates are exclusive.
+type PixelRect = (i32, i32, i32, i32);
+
+/// A spatial index mapping the pixels of a rendered `PdfPage` to the indices of the characters
+/// drawn at those pixels, as returned by the `PdfPage::render_with_text_map()` function.
+///
+/// The map divides the rendered bitmap into a uniform grid of cells, each of which records
+/// the characters overlapping it. Looking up the character at a given pixel therefore only
+/// examines the handful of characters near that pixel, making [PdfPageTextHitMap::char_index_at()]
+/// cheap enough to call on every mouse move when implementing interactive text selection.
+///
+/// Character bounds are taken from Pdfium's loose character boxes, which cover the full height
+/// of each line of text, so that there are no gaps between characters on the same line.
+pub struct PdfPageTextHitMap {
+    width: Pixels,
+    height: Pixels,
+    columns: usize,
+    rects: Vec<(PdfPageTextCharIndex, PixelRect)>,
+    cell_offsets: Vec<usize>,
+    cell_entries: Vec<usize>,
+}
+
+impl PdfPageTextHitMap {
+    /// Creates a new [PdfPageTextHitMap] for the characters in the given [PdfPageText],
+    /// projected into pixel coordinates using the given [PdfRenderSettings].
+    pub(crate) fn new(
+        page_handle: FPDF_PAGE,
+        text: &PdfPageText,
+        settings: &PdfRenderSettings,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Self {
+        let page_to_device = |x: f32, y: f32| {
+            let mut device_x: c_int = 0;
+
+            let mut device_y: c_int = 0;
+
+            bindings
+                .is_true(bindings.FPDF_PageToDevice(
+                    page_handle,
+                    0,
+                    0,
+                    settings.width,
+                    settings.height,
+                    settings.rotate,
+                    x as c_double,
+                    y as c_double,
+                    &mut device_x,
+                    &mut device_y,
+                ))
+                .then_some((device_x, device_y))
+        };
+
+        let rects = (0..text.len())
+            .filter_map(|index| {
+                let mut bounds = FS_RECTF {
+                    left: 0.0,
+                    top: 0.0,
+                    right: 0.0,
+                    bottom: 0.0,
+                };
+
+                if !bindings.is_true(bindings.FPDFText_GetLooseCharBox(
+                    *text.handle(),
+                    index,
+                    &mut bounds,
+                )) {
+                    return None;
+                }
+
+                // The page may be rotated during rendering, so either corner of the character
+                // box may end up at the top left of the projected pixel bounds.
+
+                let (x1, y1) = page_to_device(bounds.left, bounds.top)?;
+
+                let (x2, y2) = page_to_device(bounds.right, bounds.bottom)?;
+
+                Some((
+                    index as PdfPageTextCharIndex,
+                    (x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)),
+                ))
+            })
+            .collect();
+
+        Self::from_rects(settings.width as Pixels, settings.height as Pixels, rects)
+    }
+
+    /// Creates a new [PdfPageTextHitMap] of the given pixel dimensions from the given
+    /// character pixel bounds.
+    fn from_rects(
+        width: Pixels,
+        height: Pixels,
+        rects: Vec<(PdfPageTextCharIndex, PixelRect)>,
+    ) -> Self {
+        let columns = ((width as i32 + CELL_SIZE - 1) / CELL_SIZE) as usize;
+
+        let rows = ((height as i32 + CELL_SIZE - 1) / CELL_SIZE) as usize;
+
+        // Discard any characters lying entirely outside the bitmap, and clamp the remainder
+        // to the cells they overlap.
+
+        let rects = rects
+            .into_iter()
+            .filter(|(_, (left, top, right, bottom))| {
+                columns > 0
+                    && rows > 0
+                    && right > left
+                    && bottom > top
+                    && *right > 0
+                    && *bottom > 0
+                    && *left < width as i32
+                    && *top < height as i32
+            })
+            .collect::<Vec<_>>();
+
+        let cells_for = |(left, top, right, bottom): PixelRect| {
+            let first_column = (left.max(0) / CELL_SIZE) as usize;
+
+            let last_column = (((right - 1) / CELL_SIZE) as usize).min(columns - 1);
+
+            let first_row = (top.max(0) / CELL_SIZE) as usize;
+
+            let last_row = (((bottom - 1) / CELL_SIZE) as usize).min(rows - 1);
+
+            (first_row..=last_row).flat_map(move |row| {
+                (first_column..=last_column).map(move |column| row * columns + column)
+            })
+        };
+
+        // The grid is stored in compressed sparse row form: the entries for cell n
+        // are cell_entries[cell_offsets[n]..cell_offsets[n + 1]], and each entry is an index
+        // into rects.
+
+        let mut cell_offsets = vec![0; columns * rows + 1];
+
+        for (_, rect) in rects.iter() {
+            for cell in cells_for(*rect) {
+                cell_offsets[cell + 1] += 1;
+            }
+        }
+
+        for cell in 0..columns * rows {
+            cell_offsets[cell + 1] += cell_offsets[cell];
+        }
+
+        let mut cell_entries = vec![0; cell_offsets[columns * rows]];
+
+        let mut next = cell_offsets.clone();
+
+        for (entry, (_, rect)) in rects.iter().enumerate() {
+            for cell in cells_for(*rect) {
+                cell_entries[next[cell]] = entry;
+
+                next[cell] += 1;
+            }
+        }
+
+        PdfPageTextHitMap {
+            width,
+            height,
+            columns,
+            rects,
+            cell_offsets,
+            cell_entries,
+        }
+    }
+
+    /// Returns the width, in pixels, of the rendered bitmap covered by this [PdfPageTextHitMap].
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.width
+    }
+
+    /// Returns the height, in pixels, of the rendered bitmap covered by this [PdfPageTextHitMap].
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.height
+    }
+
+    /// Returns the number of characters with visible bounds in this [PdfPageTextHitMap].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Returns `true` if this [PdfPageTextHitMap] contains no characters with visible bounds.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns the index of the character drawn at the given pixel coordinates,
+    /// or `None` if no character covers that pixel. If the bounds of several characters
+    /// overlap the pixel, the character with the lowest index is returned.
+    ///
+    /// The returned index can be passed to `PdfPageTextChars::get()` to retrieve the character.
+    pub fn char_index_at(&self, x: Pixels, y: Pixels) -> Option<PdfPageTextCharIndex> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let (x, y) = (x as i32, y as i32);
+
+        let cell = (y / CELL_SIZE) as usize * self.columns + (x / CELL_SIZE) as usize;
+
+        self.cell_entries[self.cell_offsets[cell]..self.cell_offsets[cell + 1]]
+            .iter()
+            .map(|entry| &self.rects[*entry])
+            .find(|(_, (left, top, right, bottom))| {
+                x >= *left && x < *right && y >= *top && y < *bottom
+            })
+            .map(|(index, _)| *index)
+    }
+
+    /// Returns the pixel bounds of the character with the given index, as left, top, right,
+    /// and bottom pixel coordinates, or `None` if the character has no visible bounds in
+    /// this [PdfPageTextHitMap]. The right and bottom coordinates are exclusive.
+    pub fn char_bounds(&self, index: PdfPageTextCharIndex) -> Option<(i32, i32, i32, i32)> {
+        self.rects
+            .binary_search_by_key(&index, |(index, _)| *index)
+            .ok()
+            .map(|entry| self.rects[entry].1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_index_at() {
+        let map = PdfPageTextHitMap::from_rects(
+            100,
+            80,
+            vec![
+                (0, (10, 10, 20, 30)),
+                (1, (20, 10, 40, 30)),
+                (2, (30, 60, 90, 90)),
+                (3, (200, 200, 210, 210)),
+            ],
+        );
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.char_index_at(15, 20), Some(0));
+        assert_eq!(map.char_index_at(20, 20), Some(1));
+        assert_eq!(map.char_index_at(39, 29), Some(1));
+        assert_eq!(map.char_index_at(40, 20), None);
+        assert_eq!(map.char_index_at(89, 79), Some(2));
+        assert_eq!(map.char_index_at(5, 5), None);
+        assert_eq!(map.char_index_at(100, 10), None);
+        assert_eq!(map.char_bounds(1), Some((20, 10, 40, 30)));
+        assert_eq!(map.char_bounds(3), None);
+    }
+}
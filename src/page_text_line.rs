@@ -0,0 +1,67 @@
+//! Defines the [PdfPageTextLine] struct, exposing functionality related to a single line of text
+//! extracted from a `PdfPageText` object.
+
+use crate::page::{PdfPoints, PdfRect};
+
+/// A single line of text extracted from a `PdfPageText` object.
+///
+/// Lines are reconstructed from the positions of the individual characters on the page,
+/// so the text of a line is returned in the order in which its characters are defined in the
+/// document. Use the `PdfPageText::text_lines()` function to retrieve all the lines on a page.
+#[derive(Debug, Clone)]
+pub struct PdfPageTextLine {
+    text: String,
+    bounds: PdfRect,
+    gap_before: Option<PdfPoints>,
+    is_paragraph_start: bool,
+}
+
+impl PdfPageTextLine {
+    #[inline]
+    pub(crate) fn new(
+        text: String,
+        bounds: PdfRect,
+        gap_before: Option<PdfPoints>,
+        is_paragraph_start: bool,
+    ) -> Self {
+        PdfPageTextLine {
+            text,
+            bounds,
+            gap_before,
+            is_paragraph_start,
+        }
+    }
+
+    /// Returns the text of this [PdfPageTextLine].
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding box of this [PdfPageTextLine].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the vertical distance between the bottom of the previous line and the top of
+    /// this [PdfPageTextLine], or `None` if this is the first line on the page.
+    ///
+    /// The value will be negative if this line is positioned above the previous line,
+    /// for instance at the start of a new column.
+    #[inline]
+    pub fn gap_before(&self) -> Option<PdfPoints> {
+        self.gap_before
+    }
+
+    /// Returns `true` if this [PdfPageTextLine] appears to start a new paragraph.
+    ///
+    /// This is a heuristic based on the vertical distance between this line and the previous
+    /// line. A line is considered to start a new paragraph if it is the first line on the page,
+    /// if it is positioned above the previous line, or if the gap separating it from the
+    /// previous line is larger than half the height of the previous line.
+    #[inline]
+    pub fn is_paragraph_start(&self) -> bool {
+        self.is_paragraph_start
+    }
+}
@@ -0,0 +1,169 @@
+//! Defines the [PdfPageTextSearch] struct, exposing functionality related to searching
+//! the collection of Unicode characters visible in a single `PdfPage`.
+
+use crate::bindgen::{FPDF_CONSECUTIVE, FPDF_MATCHCASE, FPDF_MATCHWHOLEWORD, FPDF_SCHHANDLE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::page_text::PdfPageText;
+use crate::page_text_chars::PdfPageTextChars;
+use std::os::raw::c_ulong;
+
+/// Configures the search behaviour of a [PdfPageTextSearch].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PdfSearchOptions {
+    match_case: bool,
+    match_whole_word: bool,
+    match_consecutive: bool,
+}
+
+impl PdfSearchOptions {
+    /// Creates a new [PdfSearchOptions] object with all settings initialized with their
+    /// default values.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Controls whether the search should match the case of the search text.
+    /// By default, the search is case-insensitive.
+    #[inline]
+    pub fn match_case(mut self, do_match_case: bool) -> Self {
+        self.match_case = do_match_case;
+
+        self
+    }
+
+    /// Controls whether the search should only match whole words.
+    /// By default, the search matches partial words.
+    #[inline]
+    pub fn match_whole_word(mut self, do_match_whole_word: bool) -> Self {
+        self.match_whole_word = do_match_whole_word;
+
+        self
+    }
+
+    /// Controls whether the search should consider overlapping matches. For instance,
+    /// searching for "aa" in "aaa" will find one match by default, but two matches if
+    /// consecutive matching is enabled.
+    #[inline]
+    pub fn match_consecutive(mut self, do_match_consecutive: bool) -> Self {
+        self.match_consecutive = do_match_consecutive;
+
+        self
+    }
+
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_ulong {
+        let mut flags = 0;
+
+        if self.match_case {
+            flags |= FPDF_MATCHCASE;
+        }
+
+        if self.match_whole_word {
+            flags |= FPDF_MATCHWHOLEWORD;
+        }
+
+        if self.match_consecutive {
+            flags |= FPDF_CONSECUTIVE;
+        }
+
+        flags as c_ulong
+    }
+}
+
+/// A stateful search for a given string of text within a [PdfPageText] object.
+///
+/// The search retains its position between calls, so successive calls to
+/// [PdfPageTextSearch::find_next()] and [PdfPageTextSearch::find_previous()] move forwards
+/// and backwards through the matches on the page in the same way as the "find next" and
+/// "find previous" buttons of a viewer's find bar. Use the `PdfPageText::search()` function
+/// to start a new search.
+///
+/// [PdfPageTextSearch] also implements [Iterator], yielding each successive match, so all
+/// matches on a page can be collected in a single call. Use `PdfPageTextChars::bounding_rects()`
+/// to retrieve the areas of the page covered by a match for highlighting.
+pub struct PdfPageTextSearch<'a> {
+    handle: FPDF_SCHHANDLE,
+    text: &'a PdfPageText<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageTextSearch<'a> {
+    pub(crate) fn from_pdfium(
+        handle: FPDF_SCHHANDLE,
+        text: &'a PdfPageText<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageTextSearch {
+            handle,
+            text,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageTextSearch].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Advances this [PdfPageTextSearch] to the next match after the current position,
+    /// returning the characters of the match, or `None` if there are no further matches.
+    #[inline]
+    pub fn find_next(&self) -> Option<PdfPageTextChars<'a>> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFText_FindNext(self.handle))
+        {
+            self.current_match()
+        } else {
+            None
+        }
+    }
+
+    /// Moves this [PdfPageTextSearch] back to the previous match before the current position,
+    /// returning the characters of the match, or `None` if there are no earlier matches.
+    #[inline]
+    pub fn find_previous(&self) -> Option<PdfPageTextChars<'a>> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFText_FindPrev(self.handle))
+        {
+            self.current_match()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the characters of the match at the current position of this [PdfPageTextSearch].
+    fn current_match(&self) -> Option<PdfPageTextChars<'a>> {
+        let start = self.bindings.FPDFText_GetSchResultIndex(self.handle);
+
+        let len = self.bindings.FPDFText_GetSchCount(self.handle);
+
+        if start < 0 || len <= 0 {
+            None
+        } else {
+            Some(PdfPageTextChars::new(self.text, start, len, self.bindings))
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageTextSearch<'a> {
+    type Item = PdfPageTextChars<'a>;
+
+    /// Advances this [PdfPageTextSearch] to the next match, as for
+    /// [PdfPageTextSearch::find_next()].
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.find_next()
+    }
+}
+
+impl<'a> Drop for PdfPageTextSearch<'a> {
+    /// Closes this [PdfPageTextSearch], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFText_FindClose(self.handle);
+    }
+}
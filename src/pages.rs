@@ -2,17 +2,19 @@
 //! `PdfDocument`.
 
 use crate::bindgen::{
-    size_t, FPDF_DOCUMENT, FPDF_PAGE, PAGEMODE_FULLSCREEN, PAGEMODE_UNKNOWN,
+    size_t, FPDF_DOCUMENT, FPDF_PAGE, FS_SIZEF, PAGEMODE_FULLSCREEN, PAGEMODE_UNKNOWN,
     PAGEMODE_USEATTACHMENTS, PAGEMODE_USENONE, PAGEMODE_USEOC, PAGEMODE_USEOUTLINES,
     PAGEMODE_USETHUMBS,
 };
 use crate::bindings::PdfiumLibraryBindings;
+use crate::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::page::{PdfPage, PdfPageContentRegenerationStrategy, PdfPoints};
 use crate::page_index_cache::PdfPageIndexCache;
 use crate::page_object_group::PdfPageGroupObject;
 use crate::page_size::PdfPagePaperSize;
+use crate::render_config::PdfRenderConfig;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ops::{Range, RangeInclusive};
@@ -69,6 +71,24 @@ impl PdfPageMode {
     }
 }
 
+/// Determines how [PdfPages::render_iter()] handles a page that fails to load or render,
+/// for instance because the page's content is corrupt.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfRenderErrorPolicy {
+    /// The error is returned for the failed page. Iteration continues with the next page,
+    /// so callers can choose whether to stop at the first error or handle each error inline.
+    Propagate,
+
+    /// The error is logged and the failed page is omitted.
+    Skip,
+
+    /// The error is logged and a blank placeholder bitmap is returned for the failed page,
+    /// sized as the page would have been rendered and filled with the clear color configured
+    /// in the `PdfRenderConfig`. If the size of the page cannot be determined, the original
+    /// error is returned instead.
+    Placeholder,
+}
+
 /// The collection of [PdfPage] objects inside a [PdfDocument].
 pub struct PdfPages<'a> {
     document: &'a PdfDocument<'a>,
@@ -126,19 +146,59 @@ impl<'a> PdfPages<'a> {
             return Err(PdfiumError::PageIndexOutOfBounds);
         }
 
-        let handle = self
-            .bindings()
-            .FPDF_LoadPage(*self.document().handle(), index as c_int);
+        let document = *self.document().handle();
+
+        let cached_page = self.document().page_cache().borrow_mut().get(index);
+
+        let handle = match cached_page.as_ref() {
+            Some(cached_page) => cached_page.handle(),
+            None => self.bindings().FPDF_LoadPage(document, index as c_int),
+        };
 
-        let result = self.pdfium_page_handle_to_result(index, handle);
+        let mut result = self.pdfium_page_handle_to_result(index, handle);
 
-        if result.is_ok() {
-            PdfPageIndexCache::set_index_for_page(*self.document.handle(), handle, index);
+        if let Ok(page) = result.as_mut() {
+            PdfPageIndexCache::set_index_for_page(document, handle, index);
+
+            page.set_cached_page(cached_page.or_else(|| {
+                self.document().page_cache().borrow_mut().insert(
+                    index,
+                    handle,
+                    document,
+                    self.bindings(),
+                )
+            }));
         }
 
         result
     }
 
+    /// Enables caching of loaded pages in the [PdfDocument] containing this [PdfPages]
+    /// collection, retaining up to the given number of the most recently retrieved pages.
+    /// A capacity of zero disables caching and releases any cached pages. Caching is
+    /// disabled by default.
+    ///
+    /// When caching is enabled, retrieving a cached page using [PdfPages::get()] reuses
+    /// the already-loaded page rather than asking Pdfium to load and parse the page again.
+    /// This can substantially improve performance when the same pages are accessed
+    /// repeatedly, for instance as the user scrolls back and forth in a viewer.
+    ///
+    /// The cache belongs to the containing [PdfDocument], so the setting persists across
+    /// calls to `PdfDocument::pages()`. All [PdfPage] objects retrieved for the same cached
+    /// page share the same underlying Pdfium page, so changes made through one are visible
+    /// through the others. A page evicted from the cache is closed once every [PdfPage]
+    /// using it has been dropped, and all cached pages are closed when the document is dropped.
+    #[inline]
+    pub fn with_cache(self, capacity: usize) -> Self {
+        self.document().page_cache().borrow_mut().set_capacity(
+            capacity,
+            *self.document().handle(),
+            self.bindings(),
+        );
+
+        self
+    }
+
     /// Returns the first [PdfPage] in this [PdfPages] collection.
     #[inline]
     pub fn first(&self) -> Result<PdfPage<'a>, PdfiumError> {
@@ -197,6 +257,8 @@ impl<'a> PdfPages<'a> {
         );
 
         if let Ok(page) = result.as_ref() {
+            self.document().invalidate_page_cache();
+
             PdfPageIndexCache::insert_pages_at_index(*self.document.handle(), index, 1);
             PdfPageIndexCache::set_index_for_page(*self.document.handle(), *page.handle(), index);
         }
@@ -228,6 +290,8 @@ impl<'a> PdfPages<'a> {
         if let Some(error) = self.bindings().get_pdfium_last_error() {
             Err(PdfiumError::PdfiumLibraryInternalError(error))
         } else {
+            self.document().invalidate_page_cache();
+
             PdfPageIndexCache::delete_pages_at_index(*self.document.handle(), index, 1);
 
             Ok(())
@@ -285,13 +349,17 @@ impl<'a> PdfPages<'a> {
         pages: &str,
         destination_page_index: PdfPageIndex,
     ) -> Result<(), PdfiumError> {
-        Self::copy_pages_between_documents(
+        let result = Self::copy_pages_between_documents(
             *source.handle(),
             pages,
             *self.document.handle(),
             destination_page_index,
             self.bindings(),
-        )
+        );
+
+        self.document().invalidate_page_cache();
+
+        result
     }
 
     /// Copies one or more pages, specified using a user-friendly page range string,
@@ -344,13 +412,17 @@ impl<'a> PdfPages<'a> {
         source_page_range: RangeInclusive<PdfPageIndex>,
         destination_page_index: PdfPageIndex,
     ) -> Result<(), PdfiumError> {
-        Self::copy_page_range_between_documents(
+        let result = Self::copy_page_range_between_documents(
             *source.handle(),
             source_page_range,
             *self.document.handle(),
             destination_page_index,
             self.bindings(),
-        )
+        );
+
+        self.document().invalidate_page_cache();
+
+        result
     }
 
     /// Copies one or more pages with the given range of indices from one raw document handle
@@ -399,6 +471,11 @@ impl<'a> PdfPages<'a> {
     /// For finer control over which pages are imported, and where they should be inserted,
     /// use one of the [PdfPages::copy_page_from_document()], [PdfPages::copy_pages_from_document()],
     ///  or [PdfPages::copy_page_range_from_document()] functions.
+    ///
+    /// Only pages are copied. The bookmarks of the source [PdfDocument] are not carried over,
+    /// and link annotations on the copied pages keep whatever destinations Pdfium assigns to
+    /// them during the import; Pdfium provides no API for creating bookmarks or for rewriting
+    /// link destinations, so neither can be remapped onto the merged document.
     #[inline]
     pub fn append(&mut self, document: &PdfDocument) -> Result<(), PdfiumError> {
         self.copy_page_range_from_document(
@@ -597,12 +674,108 @@ impl<'a> PdfPages<'a> {
     pub fn iter(&self) -> PdfPagesIterator {
         PdfPagesIterator::new(self)
     }
+
+    /// Returns an iterator that renders each page in this [PdfPages] collection in turn
+    /// using the given [PdfRenderConfig], yielding the index of each page together with
+    /// the result of rendering it.
+    ///
+    /// A page that fails to load or render does not end the iteration. Instead, the given
+    /// [PdfRenderErrorPolicy] determines whether the error is returned for that page, whether
+    /// the page is skipped, or whether a blank placeholder bitmap is returned in its place.
+    /// This allows a batch job to process every renderable page of a damaged document.
+    ///
+    /// Pages are loaded and rendered one at a time, so only a single page is held in memory
+    /// by the iterator at any time.
+    #[inline]
+    pub fn render_iter<'b>(
+        &'b self,
+        config: &'b PdfRenderConfig,
+        policy: PdfRenderErrorPolicy,
+    ) -> PdfPagesRenderIterator<'a, 'b> {
+        PdfPagesRenderIterator::new(self, config, policy)
+    }
+
+    /// Loads and renders the page at the given index using the given [PdfRenderConfig].
+    fn render_page(
+        &self,
+        index: PdfPageIndex,
+        config: &PdfRenderConfig,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let page = self.get(index)?;
+
+        let settings = config.apply_to_page(&page);
+
+        let mut bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::from_pdfium(settings.format as u32).unwrap_or_default(),
+            self.bindings(),
+        )?;
+
+        page.render_into_bitmap_with_config(&mut bitmap, config)?;
+
+        Ok(bitmap)
+    }
+
+    /// Creates a blank bitmap sized as the page at the given index would have been rendered
+    /// using the given [PdfRenderConfig], filled with the configured clear color. The page
+    /// itself is not loaded.
+    fn render_placeholder(
+        &self,
+        index: PdfPageIndex,
+        config: &PdfRenderConfig,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let mut size = FS_SIZEF {
+            width: 0.0,
+            height: 0.0,
+        };
+
+        if !self
+            .bindings()
+            .is_true(self.bindings().FPDF_GetPageSizeByIndexF(
+                *self.document().handle(),
+                index as c_int,
+                &mut size,
+            ))
+        {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                self.bindings()
+                    .get_pdfium_last_error()
+                    .unwrap_or(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let settings =
+            config.apply_to_page_size(PdfPoints::new(size.width), PdfPoints::new(size.height));
+
+        let bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::from_pdfium(settings.format as u32).unwrap_or_default(),
+            self.bindings(),
+        )?;
+
+        self.bindings().FPDFBitmap_FillRect(
+            *bitmap.handle(),
+            0,
+            0,
+            settings.width,
+            settings.height,
+            settings.clear_color,
+        );
+
+        Ok(bitmap)
+    }
 }
 
 /// An iterator over all the [PdfPage] objects in a [PdfPages] collection.
+///
+/// The iterator can be traversed in either direction; call `rev()` to iterate
+/// over the pages in the collection from back to front.
 pub struct PdfPagesIterator<'a> {
     pages: &'a PdfPages<'a>,
     next_index: PdfPageIndex,
+    next_back_index: PdfPageIndex,
 }
 
 impl<'a> PdfPagesIterator<'a> {
@@ -611,6 +784,7 @@ impl<'a> PdfPagesIterator<'a> {
         PdfPagesIterator {
             pages,
             next_index: 0,
+            next_back_index: pages.len(),
         }
     }
 }
@@ -619,10 +793,265 @@ impl<'a> Iterator for PdfPagesIterator<'a> {
     type Item = PdfPage<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.next_back_index {
+            return None;
+        }
+
         let next = self.pages.get(self.next_index);
 
         self.next_index += 1;
 
         next.ok()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Iteration ends early if a page fails to load, so only an upper bound is known.
+
+        let remaining = self.next_back_index.saturating_sub(self.next_index) as usize;
+
+        (0, Some(remaining))
+    }
+}
+
+impl<'a> DoubleEndedIterator for PdfPagesIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next_back_index <= self.next_index {
+            return None;
+        }
+
+        self.next_back_index -= 1;
+
+        self.pages.get(self.next_back_index).ok()
+    }
+}
+
+/// An iterator that renders each [PdfPage] in a [PdfPages] collection in turn, as returned by
+/// the [PdfPages::render_iter()] function.
+pub struct PdfPagesRenderIterator<'a, 'b> {
+    pages: &'b PdfPages<'a>,
+    config: &'b PdfRenderConfig,
+    policy: PdfRenderErrorPolicy,
+    next_index: PdfPageIndex,
+}
+
+impl<'a, 'b> PdfPagesRenderIterator<'a, 'b> {
+    #[inline]
+    pub(crate) fn new(
+        pages: &'b PdfPages<'a>,
+        config: &'b PdfRenderConfig,
+        policy: PdfRenderErrorPolicy,
+    ) -> Self {
+        PdfPagesRenderIterator {
+            pages,
+            config,
+            policy,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a, 'b> Iterator for PdfPagesRenderIterator<'a, 'b> {
+    type Item = (PdfPageIndex, Result<PdfBitmap<'a>, PdfiumError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.pages.len() {
+            let index = self.next_index;
+
+            self.next_index += 1;
+
+            let error = match self.pages.render_page(index, self.config) {
+                Ok(bitmap) => return Some((index, Ok(bitmap))),
+                Err(error) => error,
+            };
+
+            match self.policy {
+                PdfRenderErrorPolicy::Propagate => return Some((index, Err(error))),
+                PdfRenderErrorPolicy::Skip => {
+                    log::warn!(
+                        "pdfium-render::PdfPagesRenderIterator: skipping page {} after error: {:?}",
+                        index,
+                        error
+                    );
+                }
+                PdfRenderErrorPolicy::Placeholder => {
+                    log::warn!(
+                        "pdfium-render::PdfPagesRenderIterator: substituting placeholder for page {} after error: {:?}",
+                        index,
+                        error
+                    );
+
+                    return Some((
+                        index,
+                        self.pages
+                            .render_placeholder(index, self.config)
+                            .map_err(|_| error),
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_page_cache() -> Result<(), PdfiumError> {
+        // Confirms that cached pages are reused, and that a page evicted from the cache
+        // remains usable while still in scope.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let pages = document.pages().with_cache(1);
+
+        let first = pages.get(0)?;
+
+        assert_eq!(pages.get(0)?.handle(), first.handle());
+
+        // Loading a second page evicts the first page from the cache, but the first page
+        // must not be closed while it is still in use.
+
+        let second = pages.get(1)?;
+
+        assert_eq!(pages.get(1)?.handle(), second.handle());
+        assert_ne!(pages.get(0)?.handle(), first.handle());
+        assert!(first.width().value > 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_cache_after_insert_and_delete() -> Result<(), PdfiumError> {
+        // Confirms that the page cache never returns a page that has moved to a different
+        // index, or that has been deleted, as a result of inserting or deleting pages.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut pages = document.pages().with_cache(3);
+
+        pages.create_page_at_end(PdfPagePaperSize::a3())?;
+        pages.create_page_at_end(PdfPagePaperSize::a4())?;
+        pages.create_page_at_end(PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A5))?;
+
+        // Load every page into the cache.
+
+        for index in pages.as_range() {
+            pages.get(index)?;
+        }
+
+        pages.create_page_at_start(PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A6))?;
+
+        assert_eq!(
+            pages.get(0)?.width(),
+            PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A6).width()
+        );
+        assert_eq!(pages.get(1)?.width(), PdfPagePaperSize::a3().width());
+        assert_eq!(
+            pages.get(3)?.width(),
+            PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A5).width()
+        );
+
+        pages.get(1)?.delete()?;
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(
+            pages.get(0)?.width(),
+            PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A6).width()
+        );
+        assert_eq!(pages.get(1)?.width(), PdfPagePaperSize::a4().width());
+        assert_eq!(
+            pages.get(2)?.width(),
+            PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A5).width()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_cache_with_evicted_page_in_use() -> Result<(), PdfiumError> {
+        // Confirms that dropping an evicted page that shares its index with a newer cached
+        // page does not disturb the cached page.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut pages = document.pages().with_cache(1);
+
+        pages.create_page_at_end(PdfPagePaperSize::a3())?;
+        pages.create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let evicted = pages.get(0)?;
+
+        pages.get(1)?;
+
+        let reloaded = pages.get(0)?;
+
+        assert_ne!(evicted.handle(), reloaded.handle());
+
+        drop(evicted);
+
+        assert_eq!(pages.get(0)?.handle(), reloaded.handle());
+
+        pages.create_page_at_start(PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A5))?;
+
+        assert_eq!(
+            pages.get(0)?.width(),
+            PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::A5).width()
+        );
+        assert_eq!(pages.get(1)?.width(), PdfPagePaperSize::a3().width());
+        assert_eq!(pages.get(2)?.width(), PdfPagePaperSize::a4().width());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_iter_error_policies() -> Result<(), PdfiumError> {
+        // The second of the three pages in the given test PDF file contains a shading object.
+        // Pdfium cannot hide shading objects, so rendering that page with a set of visible
+        // layers fails, while the other two pages render successfully.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/render-error-test.pdf", None)?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_width(100)
+            .set_visible_layers(&["Layer"]);
+
+        let skipped = document
+            .pages()
+            .render_iter(&config, PdfRenderErrorPolicy::Skip)
+            .map(|(index, result)| result.map(|_| index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(skipped, vec![0, 2]);
+
+        let placeholders = document
+            .pages()
+            .render_iter(&config, PdfRenderErrorPolicy::Placeholder)
+            .map(|(_, result)| result.map(|bitmap| (bitmap.width(), bitmap.height())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(placeholders, vec![(100, 150); 3]);
+
+        let propagated = document
+            .pages()
+            .render_iter(&config, PdfRenderErrorPolicy::Propagate)
+            .map(|(index, result)| (index, result.is_ok()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(propagated, vec![(0, true), (1, false), (2, true)]);
+
+        Ok(())
+    }
 }
@@ -403,6 +403,46 @@ impl Pdfium {
         })
     }
 
+    /// Returns the error code reported by Pdfium for the most recent failed operation, if any.
+    ///
+    /// Pdfium only updates its error code when loading a document or when certain operations
+    /// fail, and most successful operations do not reset it, so the returned error may relate
+    /// to an earlier operation than the most recent one. Functions in this crate that fail
+    /// because of a Pdfium error already include the error code in the returned [PdfiumError].
+    #[inline]
+    pub fn last_error(&self) -> Option<PdfiumInternalError> {
+        self.bindings.get_pdfium_last_error()
+    }
+
+    /// Calls the given function up to the given number of times, until it succeeds, returning
+    /// the result of the first successful call, or the [PdfiumError] returned by the final
+    /// attempt if every attempt fails. The function is always called at least once.
+    ///
+    /// This is intended for idempotent operations that may fail transiently, such as loading
+    /// a document using a custom reader backed by an unreliable source. Every error is
+    /// retried, irrespective of its cause.
+    ///
+    /// Pdfium provides no way to reset its own error code between attempts, so each attempt's
+    /// outcome is determined solely by the result returned by the given function, rather than by
+    /// [Pdfium::last_error()].
+    pub fn with_retry<T>(
+        &self,
+        attempts: usize,
+        mut f: impl FnMut() -> Result<T, PdfiumError>,
+    ) -> Result<T, PdfiumError> {
+        let mut result = f();
+
+        for _ in 1..attempts {
+            if result.is_ok() {
+                break;
+            }
+
+            result = f();
+        }
+
+        result
+    }
+
     /// Returns a [PdfDocument] from the given `FPDF_DOCUMENT` handle, if possible.
     pub(crate) fn pdfium_document_handle_to_result(
         handle: crate::bindgen::FPDF_DOCUMENT,
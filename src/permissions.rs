@@ -31,6 +31,15 @@ pub enum PdfSecurityHandlerRevision {
     Revision2,
     Revision3,
     Revision4,
+
+    /// Revision 5 of the standard security handler, using 256-bit AES encryption.
+    /// This revision was introduced in Adobe's extensions to PDF 1.7 and has since been
+    /// deprecated in favour of [PdfSecurityHandlerRevision::Revision6].
+    Revision5,
+
+    /// Revision 6 of the standard security handler, using 256-bit AES encryption,
+    /// as defined in PDF 2.0.
+    Revision6,
 }
 
 impl PdfSecurityHandlerRevision {
@@ -40,6 +49,8 @@ impl PdfSecurityHandlerRevision {
             2 => Some(PdfSecurityHandlerRevision::Revision2),
             3 => Some(PdfSecurityHandlerRevision::Revision3),
             4 => Some(PdfSecurityHandlerRevision::Revision4),
+            5 => Some(PdfSecurityHandlerRevision::Revision5),
+            6 => Some(PdfSecurityHandlerRevision::Revision6),
             _ => None,
         }
     }
@@ -82,7 +93,8 @@ impl<'a> PdfPermissions<'a> {
     }
 
     /// Returns the revision of the standard security handler used by the containing `PdfDocument`.
-    /// As of PDF version 1.7, possible revision numbers are 2, 3, or 4.
+    /// As of PDF version 2.0, possible revision numbers are 2, 3, 4, or 6; revision 5 was used
+    /// by Adobe's extensions to PDF 1.7 and may still be encountered in older documents.
     pub fn security_handler_revision(&self) -> Result<PdfSecurityHandlerRevision, PdfiumError> {
         PdfSecurityHandlerRevision::from_pdfium(
             self.bindings()
@@ -101,7 +113,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Revision2 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
                     && permissions.contains(FpdfPermissions::V3_CAN_PRINT_HIGH_QUALITY_BIT_12)
             }
@@ -120,7 +135,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Unprotected | PdfSecurityHandlerRevision::Revision2 => {
                 false
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
                     && !permissions.contains(FpdfPermissions::V3_CAN_PRINT_HIGH_QUALITY_BIT_12)
             }
@@ -140,7 +158,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Revision2 => {
                 permissions.contains(FpdfPermissions::CAN_MODIFY_BIT_4)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::V3_CAN_ASSEMBLE_DOCUMENT_BIT_11)
             }
         };
@@ -174,7 +195,10 @@ impl<'a> PdfPermissions<'a> {
                 permissions.contains(FpdfPermissions::CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_5)
             }
             // TODO: AJRC - 27/5/22 - what operations are permitted by bit 10 but prevented by bit 5?
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::V3_CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_10)
             }
         };
@@ -192,7 +216,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Revision2 => {
                 permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::V3_CAN_FORM_FILL_BIT_9)
             }
         };
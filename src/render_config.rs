@@ -2,17 +2,18 @@
 //! the rendering of `PdfBitmap` objects from one or more [PdfPage] objects.
 
 use crate::bindgen::{
-    FPDF_ANNOT, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE, FPDF_LCD_TEXT,
-    FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE, FPDF_RENDER_LIMITEDIMAGECACHE,
-    FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH, FPDF_RENDER_NO_SMOOTHTEXT,
-    FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
+    FPDF_ANNOT, FPDF_COLORSCHEME, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE,
+    FPDF_LCD_TEXT, FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE,
+    FPDF_RENDER_LIMITEDIMAGECACHE, FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH,
+    FPDF_RENDER_NO_SMOOTHTEXT, FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
 };
 use crate::bitmap::{PdfBitmapFormat, PdfBitmapRotation, Pixels};
 use crate::color::PdfColor;
 use crate::error::PdfiumError;
 use crate::form::PdfFormFieldType;
 use crate::page::PdfPageOrientation::{Landscape, Portrait};
-use crate::page::{PdfPage, PdfPageOrientation, PdfPoints};
+use crate::page::{PdfPage, PdfPageOrientation, PdfPoints, PdfRect};
+use crate::transform::PdfMatrix;
 use std::os::raw::c_int;
 use vecmath::{mat3_det, row_mat3_mul, Matrix3};
 
@@ -77,10 +78,15 @@ pub struct PdfRenderConfig {
     format: PdfBitmapFormat,
     do_clear_bitmap_before_rendering: bool,
     clear_color: PdfColor,
+    color_scheme: Option<(PdfColor, PdfColor, PdfColor, PdfColor)>,
     do_render_form_data: bool,
     form_field_highlight: Option<Vec<(PdfFormFieldType, PdfColor)>>,
+    form_field_highlight_alpha: Option<u8>,
     transformation_matrix: Matrix3<f32>,
     clip_rect: Option<(Pixels, Pixels, Pixels, Pixels)>,
+    source_region: Option<PdfRect>,
+    visible_layers: Option<Vec<String>>,
+    do_premultiply_alpha: bool,
 
     // The fields below set Pdfium's page rendering flags. Coverage for the
     // FPDF_DEBUG_INFO and FPDF_NO_CATCH flags is omitted since they are obsolete.
@@ -115,10 +121,15 @@ impl PdfRenderConfig {
             format: PdfBitmapFormat::default(),
             do_clear_bitmap_before_rendering: true,
             clear_color: PdfColor::SOLID_WHITE,
+            color_scheme: None,
             do_render_form_data: true,
             form_field_highlight: None,
+            form_field_highlight_alpha: None,
             transformation_matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
             clip_rect: None,
+            source_region: None,
+            visible_layers: None,
+            do_premultiply_alpha: false,
             do_set_flag_render_annotations: true,
             do_set_flag_use_lcd_text_rendering: false,
             do_set_flag_no_native_text: false,
@@ -140,10 +151,14 @@ impl PdfRenderConfig {
 
     /// Applies settings suitable for generating a thumbnail.
     ///
-    /// * The source [PdfPage] will be rendered with a maximum width and height of the given
-    /// pixel size.
+    /// * The source [PdfPage] will be scaled to fit within a square of the given pixel size,
+    ///   so that its longest edge is no larger than the given size. The aspect ratio of the
+    ///   source page will be maintained.
     /// * The page will not be rotated, irrespective of its orientation.
-    /// * Image quality settings will be reduced to improve performance.
+    /// * The destination bitmap will be filled with solid white before rendering, so that
+    ///   pages with transparent backgrounds do not render against black.
+    /// * Image quality settings will be reduced and Pdfium's image cache size will be limited
+    ///   to improve performance.
     /// * Annotations and user-filled form field data will not be rendered.
     ///
     /// These settings are applied to this [PdfRenderConfig] object immediately and can be
@@ -151,13 +166,20 @@ impl PdfRenderConfig {
     /// [PdfRenderConfig::rotate()] can specify a custom rotation setting that will apply
     /// to the thumbnail.
     #[inline]
-    pub fn thumbnail(self, size: Pixels) -> Self {
-        self.set_target_size(size, size)
+    pub fn thumbnail(mut self, size: Pixels) -> Self {
+        self.scale_width_factor = None;
+        self.scale_height_factor = None;
+        self.target_height = None;
+
+        self.set_target_width(size)
             .set_maximum_width(size)
             .set_maximum_height(size)
             .rotate(PdfBitmapRotation::None, false)
+            .clear_before_rendering(true)
+            .set_clear_color(PdfColor::SOLID_WHITE)
             .use_print_quality(false)
             .set_image_smoothing(false)
+            .limit_render_image_cache_size(true)
             .render_annotations(false)
             .render_form_data(false)
     }
@@ -262,7 +284,13 @@ impl PdfRenderConfig {
     }
 
     /// Applies the given clockwise rotation setting to the [PdfPage] during rendering, irrespective
-    /// of its orientation. If the given flag is set to `true` then any maximum
+    /// of its orientation.
+    ///
+    /// The rotation is applied on top of any intrinsic rotation specified by the page itself
+    /// (as returned by `PdfPage::rotation()`), and does not modify the page; this makes it
+    /// suitable for implementing a "rotate view" function in a viewer.
+    ///
+    /// If the given flag is set to `true` then any maximum
     /// constraint on the final pixel width set by a call to [PdfRenderConfig::set_maximum_width()]
     /// will be rotated so it becomes a constraint on the final pixel height, and any
     /// maximum constraint on the final pixel height set by a call to [PdfRenderConfig::set_maximum_height()]
@@ -342,6 +370,10 @@ impl PdfRenderConfig {
 
     /// Sets the pixel format that will be used during rendering of the [PdfPage].
     /// The default is [PdfBitmapFormat::BGRA].
+    ///
+    /// Formats without an alpha channel use less memory: [PdfBitmapFormat::BGR] uses three
+    /// bytes per pixel, and [PdfBitmapFormat::Gray] uses only one byte per pixel, which can
+    /// significantly reduce memory use when rendering text-heavy documents at high resolution.
     #[inline]
     pub fn set_format(mut self, format: PdfBitmapFormat) -> Self {
         self.format = format;
@@ -369,6 +401,43 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Overrides the colors used to fill and stroke paths and text during rendering of
+    /// the [PdfPage], replacing the colors specified by the page content. This is primarily
+    /// useful for rendering pages in a "dark mode" color scheme, for example light text on
+    /// a dark background. The page background is controlled by [PdfRenderConfig::set_clear_color()].
+    ///
+    /// Images and shadings are not affected by the color scheme.
+    ///
+    /// Pdfium's rendering pipeline supports _either_ rendering with a color scheme _or_ rendering
+    /// with a custom transformation matrix, but not both at the same time. Applying any
+    /// transformation automatically removes any color scheme set by this function.
+    #[inline]
+    pub fn set_color_scheme(
+        mut self,
+        path_fill_color: PdfColor,
+        path_stroke_color: PdfColor,
+        text_fill_color: PdfColor,
+        text_stroke_color: PdfColor,
+    ) -> Self {
+        self.color_scheme = Some((
+            path_fill_color,
+            path_stroke_color,
+            text_fill_color,
+            text_stroke_color,
+        ));
+
+        self
+    }
+
+    /// Removes any color scheme previously set by [PdfRenderConfig::set_color_scheme()],
+    /// so that the [PdfPage] is rendered using the colors specified by its content.
+    #[inline]
+    pub fn clear_color_scheme(mut self) -> Self {
+        self.color_scheme = None;
+
+        self
+    }
+
     /// Controls whether form data widgets and user-supplied form data should be included
     /// during rendering of the [PdfPage]. The default is `true`.
     ///
@@ -497,6 +566,21 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Controls whether the color channels of the rendered image should be premultiplied
+    /// by its alpha channel once rendering is complete. The default is `false`, so that
+    /// rendered pixels have straight (non-premultiplied) alpha, as generated by Pdfium.
+    ///
+    /// Most GPU compositors expect premultiplied alpha; uploading a straight-alpha image as
+    /// a texture and compositing it typically produces fringes around the edges of
+    /// partially transparent content. This setting only affects bitmaps with an alpha channel,
+    /// i.e. bitmaps using the [PdfBitmapFormat::BGRA] pixel format.
+    #[inline]
+    pub fn premultiply_alpha(mut self, do_premultiply_alpha: bool) -> Self {
+        self.do_premultiply_alpha = do_premultiply_alpha;
+
+        self
+    }
+
     /// Controls whether rendered vector fill paths need to be stroked.
     /// The default is `false`.
     #[inline]
@@ -579,6 +663,22 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Sets the alpha value that will be applied to all form field highlight colors, irrespective
+    /// of form field type. An alpha value of 0 is fully transparent; an alpha value of 255 is
+    /// fully opaque.
+    ///
+    /// Pdfium supports only a single highlight alpha value, shared by all form field types.
+    /// If this function is not called, the alpha value of the most recently specified
+    /// highlight color is used for all highlighted form fields. If this function is called,
+    /// the given alpha value takes precedence over the alpha values of all highlight colors
+    /// passed to [PdfRenderConfig::highlight_form_fields_of_type()] and related functions.
+    #[inline]
+    pub fn set_form_field_highlight_alpha(mut self, alpha: u8) -> Self {
+        self.form_field_highlight_alpha = Some(alpha);
+
+        self
+    }
+
     /// Applies the given transformation, expressed as six values representing the six configurable
     /// elements of a nine-element 3x3 PDF transformation matrix, to a [PdfPage] during rendering.
     ///
@@ -590,7 +690,8 @@ impl PdfRenderConfig {
     /// a custom transformation matrix, but not both at the same time. Applying any transformation
     /// automatically disables rendering of form data. If you must render form data while simultaneously
     /// applying transformations, consider using the [PdfPage::flatten()] function to flatten the
-    /// form elements and form data into the containing page.
+    /// form elements and form data into the containing page. Applying any transformation also
+    /// removes any color scheme set by [PdfRenderConfig::set_color_scheme()].
     ///
     /// To move, scale, rotate, or skew a [PdfPage] during rendering, consider using one or more of the
     /// following functions. Internally they all use [PdfRenderConfig::transform()], but are
@@ -633,11 +734,27 @@ impl PdfRenderConfig {
         } else {
             self.transformation_matrix = result;
             self.do_render_form_data = false;
+            self.color_scheme = None;
 
             Ok(self)
         }
     }
 
+    /// Applies the transformation described by the given [PdfMatrix] to a [PdfPage] during rendering.
+    ///
+    /// This is equivalent to calling [PdfRenderConfig::transform()] with the six values
+    /// in the given matrix.
+    ///
+    /// Pdfium's rendering pipeline supports _either_ rendering with form data _or_ rendering with
+    /// a custom transformation matrix, but not both at the same time. Applying any transformation
+    /// automatically disables rendering of form data. If you must render form data while simultaneously
+    /// applying transformations, consider using the [PdfPage::flatten()] function to flatten the
+    /// form elements and form data into the containing page.
+    #[inline]
+    pub fn apply_matrix(self, matrix: PdfMatrix) -> Result<Self, PdfiumError> {
+        self.transform(matrix.a, matrix.b, matrix.c, matrix.d, matrix.e, matrix.f)
+    }
+
     /// Moves the origin of a [PdfPage] by the given horizontal and vertical distances during rendering.
     ///
     /// Pdfium's rendering pipeline supports _either_ rendering with form data _or_ rendering with
@@ -836,13 +953,147 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Renders only the given region of a [PdfPage], rather than the entire page.
+    ///
+    /// The region is specified in page coordinates, measured in points from the bottom-left
+    /// corner of the page. The rendered bitmap contains only the region, and all sizing settings
+    /// such as [PdfRenderConfig::set_target_width()] and [PdfRenderConfig::set_maximum_height()]
+    /// apply to the size of the region rather than to the size of the full page. This makes it
+    /// possible to render a detailed view of a small part of a page without rendering the
+    /// rest of the page at the same scale.
+    ///
+    /// The region is applied using a transformation matrix, so setting a source region
+    /// automatically disables rendering of form data and any custom color scheme,
+    /// just as [PdfRenderConfig::transform()] does.
+    #[inline]
+    pub fn set_source_region(mut self, region: PdfRect) -> Self {
+        self.source_region = Some(region);
+        self.do_render_form_data = false;
+        self.color_scheme = None;
+
+        self
+    }
+
+    /// Restricts rendering to page objects belonging to the optional content groups ("layers")
+    /// with the given names, as returned by `PdfPageObjectCommon::optional_content_group()`.
+    /// Page objects belonging to any other optional content group are not rendered. Page objects
+    /// that do not belong to any optional content group are always rendered.
+    ///
+    /// For example, to render only the roads and labels of a map:
+    ///
+    /// ```
+    /// # use pdfium_render::prelude::*;
+    /// #
+    /// let config = PdfRenderConfig::new().set_visible_layers(&["Roads", "Labels"]);
+    /// ```
+    ///
+    /// Pdfium does not provide a way to render a page with a custom set of visible layers,
+    /// so the page objects in all other layers are temporarily hidden while the page is
+    /// rendered, and restored afterwards. Page objects nested inside form XObjects are
+    /// considered individually. Pdfium continues to apply the document's own default layer
+    /// visibility, so a selected layer that the document hides by default will not be rendered.
+    /// Pdfium cannot hide shading objects, so rendering will fail with
+    /// `PdfiumError::PageObjectNotHideable` if a shading object belongs to a layer that
+    /// is not selected.
+    #[inline]
+    pub fn set_visible_layers(mut self, layers: &[&str]) -> Self {
+        self.visible_layers = Some(layers.iter().map(|layer| layer.to_string()).collect());
+
+        self
+    }
+
+    /// Returns the pixel width and height of the bitmap that would be produced by rendering
+    /// the given [PdfPage] using this [PdfRenderConfig], without actually rendering the page.
+    ///
+    /// This can be used to estimate the memory required for a render before committing to it,
+    /// or to confirm that a scale-to-fit configuration produces sensible dimensions.
+    /// The size of the buffer backing the rendered bitmap will be the product of the
+    /// returned width and height and the number of bytes per pixel of the configured
+    /// bitmap format.
+    ///
+    /// The page's own intrinsic rotation is already reflected in the page's width and height,
+    /// so it is always taken into account. Any additional rotation applied using
+    /// [PdfRenderConfig::rotate()] (or its orientation-specific variants) only swaps the
+    /// returned width and height if the rotation's `do_rotate_constraints` flag was set to `true`
+    /// and the rotation is by 90 or 270 degrees.
+    #[inline]
+    pub fn resolve_output_size(&self, page: &PdfPage) -> (Pixels, Pixels) {
+        let settings = self.apply_to_page(page);
+
+        (settings.width as Pixels, settings.height as Pixels)
+    }
+
+    /// Returns the horizontal and vertical resolution, in dots per inch, at which the given
+    /// [PdfPage] would be rendered using this [PdfRenderConfig].
+    ///
+    /// The returned values can be embedded in images encoded from the rendered bitmap,
+    /// for instance by passing them to `PdfBitmap::write_png_with_dpi()`, so that
+    /// the images print at the correct physical size.
+    pub fn resolve_output_dpi(&self, page: &PdfPage) -> (f32, f32) {
+        let settings = self.apply_to_page(page);
+
+        // A rotation of 90 or 270 degrees maps the page's height onto the bitmap's width.
+
+        let (width, height) = match self.active_source_region() {
+            Some(region) => (region.width(), region.height()),
+            None => (page.width(), page.height()),
+        };
+
+        let (horizontal_extent, vertical_extent) = if settings.rotate % 2 == 1 {
+            (height, width)
+        } else {
+            (width, height)
+        };
+
+        (
+            settings.width as f32 / horizontal_extent.to_inches(),
+            settings.height as f32 / vertical_extent.to_inches(),
+        )
+    }
+
+    /// Returns the source region set by [PdfRenderConfig::set_source_region()], if it can
+    /// be applied. Source regions are applied using the transformation matrix, so they are
+    /// ignored if rendering of form data or a custom color scheme was subsequently re-enabled.
+    #[inline]
+    fn active_source_region(&self) -> Option<PdfRect> {
+        self.source_region
+            .filter(|_| !self.do_render_form_data && self.color_scheme.is_none())
+    }
+
+    /// Returns `true` if this [PdfRenderConfig] applies a custom transformation matrix,
+    /// clipping region, or source region.
+    #[inline]
+    pub(crate) fn has_custom_transform(&self) -> bool {
+        self.transformation_matrix != [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+            || self.clip_rect.is_some()
+            || self.source_region.is_some()
+    }
+
     /// Computes the pixel dimensions and rotation settings for the given [PdfPage]
     /// based on the configuration of this [PdfRenderConfig].
     #[inline]
     pub(crate) fn apply_to_page(&self, page: &PdfPage) -> PdfRenderSettings {
-        let source_width = page.width();
+        self.apply_to_page_size(page.width(), page.height())
+    }
+
+    /// Computes the pixel dimensions and rotation settings for a page of the given size
+    /// based on the configuration of this [PdfRenderConfig].
+    pub(crate) fn apply_to_page_size(
+        &self,
+        source_width: PdfPoints,
+        source_height: PdfPoints,
+    ) -> PdfRenderSettings {
+        // If only a region of the page is to be rendered, the region takes the place
+        // of the full page when calculating the output size.
+
+        let page_height = source_height;
 
-        let source_height = page.height();
+        let source_region = self.active_source_region();
+
+        let (source_width, source_height) = match source_region {
+            Some(region) => (region.width(), region.height()),
+            None => (source_width, source_height),
+        };
 
         let source_orientation =
             PdfPageOrientation::from_width_and_height(source_width, source_height);
@@ -1004,9 +1255,29 @@ impl PdfRenderConfig {
         // or _with_ form data but _without_ transformation matrices. We need to be prepared
         // for either option. If rendering of form data is disabled then the scaled output
         // width and height and any user-specified 90-degree rotation need to be applied to the
-        // transformation matrix now.
+        // transformation matrix now. Rendering with a color scheme uses the same code path
+        // as rendering with form data, so the same applies there.
+
+        let transformation_matrix = if !self.do_render_form_data && self.color_scheme.is_none() {
+            // Move the top-left corner of any source region to the origin, so that only
+            // the region falls within the bounds of the output bitmap.
+
+            let initial = match source_region {
+                Some(region) => row_mat3_mul(
+                    self.transformation_matrix,
+                    [
+                        [1.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0],
+                        [
+                            -region.left.value,
+                            region.top.value - page_height.value,
+                            1.0,
+                        ],
+                    ],
+                ),
+                None => self.transformation_matrix,
+            };
 
-        let transformation_matrix = if !self.do_render_form_data {
             let result = if target_rotation != PdfBitmapRotation::None {
                 // Translate the origin to the center of the page before rotating.
 
@@ -1018,7 +1289,7 @@ impl PdfRenderConfig {
                 };
 
                 let result = row_mat3_mul(
-                    self.transformation_matrix,
+                    initial,
                     [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [delta_x, delta_y, 1.0]],
                 );
 
@@ -1035,7 +1306,7 @@ impl PdfRenderConfig {
                     ],
                 )
             } else {
-                self.transformation_matrix
+                initial
             };
 
             row_mat3_mul(
@@ -1057,6 +1328,16 @@ impl PdfRenderConfig {
             rotate: target_rotation.as_pdfium(),
             do_clear_bitmap_before_rendering: self.do_clear_bitmap_before_rendering,
             clear_color: self.clear_color.as_pdfium_color(),
+            color_scheme: self.color_scheme.map(
+                |(path_fill_color, path_stroke_color, text_fill_color, text_stroke_color)| {
+                    FPDF_COLORSCHEME {
+                        path_fill_color: path_fill_color.as_pdfium_argb_color(),
+                        path_stroke_color: path_stroke_color.as_pdfium_argb_color(),
+                        text_fill_color: text_fill_color.as_pdfium_argb_color(),
+                        text_stroke_color: text_stroke_color.as_pdfium_argb_color(),
+                    }
+                },
+            ),
             do_render_form_data: self.do_render_form_data,
             form_field_highlight: if !self.do_render_form_data
                 || self.form_field_highlight.is_none()
@@ -1077,6 +1358,11 @@ impl PdfRenderConfig {
                         .collect::<Vec<_>>(),
                 )
             },
+            form_field_highlight_alpha: if self.do_render_form_data {
+                self.form_field_highlight_alpha
+            } else {
+                None
+            },
             matrix: FS_MATRIX {
                 a: transformation_matrix[0][0],
                 b: transformation_matrix[0][1],
@@ -1101,6 +1387,8 @@ impl PdfRenderConfig {
                 }
             },
             render_flags: render_flags as i32,
+            visible_layers: self.visible_layers.clone(),
+            do_premultiply_alpha: self.do_premultiply_alpha,
         }
     }
 }
@@ -1122,9 +1410,13 @@ pub(crate) struct PdfRenderSettings {
     pub(crate) rotate: c_int,
     pub(crate) do_clear_bitmap_before_rendering: bool,
     pub(crate) clear_color: FPDF_DWORD,
+    pub(crate) color_scheme: Option<FPDF_COLORSCHEME>,
     pub(crate) do_render_form_data: bool,
     pub(crate) form_field_highlight: Option<Vec<(c_int, (FPDF_DWORD, u8))>>,
+    pub(crate) form_field_highlight_alpha: Option<u8>,
     pub(crate) matrix: FS_MATRIX,
     pub(crate) clipping: FS_RECTF,
     pub(crate) render_flags: c_int,
+    pub(crate) visible_layers: Option<Vec<String>>,
+    pub(crate) do_premultiply_alpha: bool,
 }
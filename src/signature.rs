@@ -6,7 +6,8 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ffi::CString;
-use std::os::raw::{c_char, c_void};
+use std::ops::Range;
+use std::os::raw::{c_char, c_int, c_void};
 
 /// A single digital signature in a `PdfDocument`.
 pub struct PdfSignature<'a> {
@@ -66,6 +67,88 @@ impl<'a> PdfSignature<'a> {
         buffer
     }
 
+    /// Returns the byte ranges of the containing document covered by this [PdfSignature].
+    ///
+    /// Each range identifies a span of bytes in the file from which the document was loaded.
+    /// Typically there are two ranges, covering the whole file except for the signature contents
+    /// themselves. The digest over these bytes is what the signature's byte data signs.
+    pub fn byte_range(&self) -> Vec<Range<usize>> {
+        // Retrieving the byte range from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetByteRange() with a null buffer; this will retrieve the number of
+        // integers in the byte range. Then we reserve a buffer of the given length and call
+        // FPDFSignatureObj_GetByteRange() again with a pointer to the buffer.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetByteRange(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no byte range given for this signature.
+
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_int> = vec![0; buffer_length as usize];
+
+        let result = self.bindings().FPDFSignatureObj_GetByteRange(
+            self.handle,
+            buffer.as_mut_ptr(),
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        // The byte range is a flat array of (offset, length) pairs.
+
+        buffer
+            .chunks_exact(2)
+            .map(|pair| {
+                let start = pair[0].max(0) as usize;
+
+                start..start + pair[1].max(0) as usize
+            })
+            .collect()
+    }
+
+    /// Returns the encoding of this [PdfSignature]'s byte data, if any, for example
+    /// `adbe.pkcs7.detached` or `ETSI.CAdES.detached`.
+    pub fn sub_filter(&self) -> Option<String> {
+        // Retrieving the sub-filter from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetSubFilter() with a null buffer; this will retrieve the length of
+        // the sub-filter in bytes. If the length is zero, then there is no sub-filter associated
+        // with this signature.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFSignatureObj_GetSubFilter() again with a pointer to the buffer;
+        // this will write the sub-filter to the buffer as an array of 7-bit ASCII characters.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetSubFilter(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no sub-filter given for this signature.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFSignatureObj_GetSubFilter(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        if let Ok(result) = CString::from_vec_with_nul(buffer) {
+            result.into_string().ok()
+        } else {
+            None
+        }
+    }
+
     /// Returns the reason for the signing, if any, as a plain text description provided by the
     /// creator of this [PdfSignature].
     pub fn reason(&self) -> Option<String> {
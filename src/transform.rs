@@ -2,6 +2,7 @@ use crate::bindgen::FS_MATRIX;
 use crate::error::PdfiumError;
 use crate::page::PdfPoints;
 use std::hash::{Hash, Hasher};
+use std::ops::Mul;
 
 pub type PdfMatrixValue = f32;
 
@@ -63,15 +64,126 @@ impl PdfMatrix {
         Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
     }
 
-    /// Creates a new [PdfMatrix] object with all matrix values set to 0.0.
+    /// Creates a new [PdfMatrix] object with matrix values a and d set to 1.0
+    /// and all other values set to 0.0.
     ///
-    /// Consider using the compile-time constant value [PdfMatrix::ZERO]
+    /// Consider using the compile-time constant value [PdfMatrix::IDENTITY]
     /// rather than calling this function directly.
     #[inline]
     pub const fn identity() -> Self {
         Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
     }
 
+    /// Creates a new [PdfMatrix] object that moves an object by the given horizontal and
+    /// vertical delta distances.
+    #[inline]
+    pub fn translation(delta_x: PdfPoints, delta_y: PdfPoints) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, delta_x.value, delta_y.value)
+    }
+
+    /// Creates a new [PdfMatrix] object that scales an object by the given horizontal and
+    /// vertical scale factors.
+    #[inline]
+    pub fn scale(
+        horizontal_scale_factor: PdfMatrixValue,
+        vertical_scale_factor: PdfMatrixValue,
+    ) -> Self {
+        Self::new(
+            horizontal_scale_factor,
+            0.0,
+            0.0,
+            vertical_scale_factor,
+            0.0,
+            0.0,
+        )
+    }
+
+    /// Creates a new [PdfMatrix] object that rotates an object counter-clockwise around its
+    /// origin by the given number of degrees.
+    #[inline]
+    pub fn rotation_counter_clockwise_degrees(degrees: PdfMatrixValue) -> Self {
+        Self::rotation_counter_clockwise_radians(degrees.to_radians())
+    }
+
+    /// Creates a new [PdfMatrix] object that rotates an object clockwise around its
+    /// origin by the given number of degrees.
+    #[inline]
+    pub fn rotation_clockwise_degrees(degrees: PdfMatrixValue) -> Self {
+        Self::rotation_counter_clockwise_degrees(-degrees)
+    }
+
+    /// Creates a new [PdfMatrix] object that rotates an object counter-clockwise around its
+    /// origin by the given number of radians.
+    #[inline]
+    pub fn rotation_counter_clockwise_radians(radians: PdfMatrixValue) -> Self {
+        let cos_theta = radians.cos();
+
+        let sin_theta = radians.sin();
+
+        Self::new(cos_theta, sin_theta, -sin_theta, cos_theta, 0.0, 0.0)
+    }
+
+    /// Creates a new [PdfMatrix] object that rotates an object clockwise around its
+    /// origin by the given number of radians.
+    #[inline]
+    pub fn rotation_clockwise_radians(radians: PdfMatrixValue) -> Self {
+        Self::rotation_counter_clockwise_radians(-radians)
+    }
+
+    /// Returns the result of multiplying this [PdfMatrix] by the given [PdfMatrix].
+    ///
+    /// The returned matrix has the same effect as applying the transformation described by
+    /// this matrix, _then_ applying the transformation described by the given matrix.
+    /// The order of multiplication is significant: `a.multiply(b)` will generally
+    /// not equal `b.multiply(a)`.
+    #[inline]
+    pub fn multiply(&self, other: PdfMatrix) -> Self {
+        Self::new(
+            self.a * other.a + self.b * other.c,
+            self.a * other.b + self.b * other.d,
+            self.c * other.a + self.d * other.c,
+            self.c * other.b + self.d * other.d,
+            self.e * other.a + self.f * other.c + other.e,
+            self.e * other.b + self.f * other.d + other.f,
+        )
+    }
+
+    /// Returns the determinant of this [PdfMatrix].
+    #[inline]
+    pub fn determinant(&self) -> PdfMatrixValue {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns the inverse of this [PdfMatrix], reversing the transformation it describes.
+    ///
+    /// An error will be returned if this matrix cannot be inverted, i.e. if its determinant is 0.
+    pub fn invert(&self) -> Result<PdfMatrix, PdfiumError> {
+        let determinant = self.determinant();
+
+        if determinant == 0.0 {
+            return Err(PdfiumError::InvalidTransformationMatrix);
+        }
+
+        Ok(Self::new(
+            self.d / determinant,
+            -self.b / determinant,
+            -self.c / determinant,
+            self.a / determinant,
+            (self.c * self.f - self.d * self.e) / determinant,
+            (self.b * self.e - self.a * self.f) / determinant,
+        ))
+    }
+
+    /// Applies the transformation described by this [PdfMatrix] to the given point,
+    /// returning the transformed point.
+    #[inline]
+    pub fn apply_to_point(&self, x: PdfPoints, y: PdfPoints) -> (PdfPoints, PdfPoints) {
+        (
+            PdfPoints::new(self.a * x.value + self.c * y.value + self.e),
+            PdfPoints::new(self.b * x.value + self.d * y.value + self.f),
+        )
+    }
+
     /// Applies the values in this [PdfMatrix] to the given transformable object.
     #[inline]
     pub fn apply(&self, transformable: &mut impl Transformable) -> Result<(), PdfiumError> {
@@ -91,6 +203,22 @@ impl PdfMatrix {
     }
 }
 
+impl Mul<PdfMatrix> for PdfMatrix {
+    type Output = PdfMatrix;
+
+    #[inline]
+    fn mul(self, rhs: PdfMatrix) -> Self::Output {
+        self.multiply(rhs)
+    }
+}
+
+impl Default for PdfMatrix {
+    #[inline]
+    fn default() -> Self {
+        PdfMatrix::IDENTITY
+    }
+}
+
 // We could derive PartialEq automatically, but it's good practice to implement PartialEq
 // by hand when implementing Hash.
 
@@ -413,3 +541,55 @@ pub trait TransformConfiguration {
         self.matrix().map(|matrix| matrix.c.atan()).unwrap_or(0.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::page::PdfPoints;
+    use crate::transform::PdfMatrix;
+
+    fn assert_matrix_eq(actual: PdfMatrix, expected: PdfMatrix) {
+        let epsilon = 0.0001;
+
+        assert!((actual.a - expected.a).abs() < epsilon, "{:?}", actual);
+        assert!((actual.b - expected.b).abs() < epsilon, "{:?}", actual);
+        assert!((actual.c - expected.c).abs() < epsilon, "{:?}", actual);
+        assert!((actual.d - expected.d).abs() < epsilon, "{:?}", actual);
+        assert!((actual.e - expected.e).abs() < epsilon, "{:?}", actual);
+        assert!((actual.f - expected.f).abs() < epsilon, "{:?}", actual);
+    }
+
+    #[test]
+    fn test_matrix_multiply_applies_left_then_right() {
+        let matrix = PdfMatrix::scale(2.0, 3.0).multiply(PdfMatrix::translation(
+            PdfPoints::new(10.0),
+            PdfPoints::new(20.0),
+        ));
+
+        let (x, y) = matrix.apply_to_point(PdfPoints::new(1.0), PdfPoints::new(1.0));
+
+        assert_eq!(x.value, 12.0);
+        assert_eq!(y.value, 23.0);
+        assert_eq!(
+            matrix,
+            PdfMatrix::scale(2.0, 3.0)
+                * PdfMatrix::translation(PdfPoints::new(10.0), PdfPoints::new(20.0))
+        );
+    }
+
+    #[test]
+    fn test_matrix_invert() {
+        let matrix = PdfMatrix::rotation_counter_clockwise_degrees(30.0)
+            .multiply(PdfMatrix::scale(2.0, 0.5))
+            .multiply(PdfMatrix::translation(
+                PdfPoints::new(-5.0),
+                PdfPoints::new(7.0),
+            ));
+
+        assert_matrix_eq(
+            matrix.multiply(matrix.invert().unwrap()),
+            PdfMatrix::IDENTITY,
+        );
+
+        assert!(PdfMatrix::ZERO.invert().is_err());
+    }
+}
@@ -17,6 +17,25 @@ pub(crate) mod pixels {
             .collect::<Vec<_>>()
     }
 
+    /// Converts the given byte array, containing pixel data encoded as four-channel BGRx,
+    /// into pixel data encoded as four-channel RGBA. The unused fourth channel is replaced
+    /// with an alpha channel with full opacity.
+    #[inline]
+    pub(crate) fn bgrx_to_rgba(bgrx: &[u8]) -> Vec<u8> {
+        bgrx.chunks_exact(4)
+            .flat_map(|channels| [channels[2], channels[1], channels[0], 255])
+            .collect::<Vec<_>>()
+    }
+
+    /// Converts the given byte array, containing pixel data encoded as single-channel grayscale,
+    /// into pixel data encoded as four-channel RGBA. A new alpha channel is created with full opacity.
+    #[inline]
+    pub(crate) fn gray_to_rgba(gray: &[u8]) -> Vec<u8> {
+        gray.iter()
+            .flat_map(|luma| [*luma, *luma, *luma, 255])
+            .collect::<Vec<_>>()
+    }
+
     /// Converts the given byte array, containing pixel data encoded as three-channel RGB,
     /// into pixel data encoded as four-channel BGRA. A new alpha channel is created with full opacity.
     #[inline]
@@ -245,6 +264,7 @@ pub(crate) mod files {
             version: 1,
             write_block: Some(write_block_from_callback),
             writer,
+            write_error: None,
         }
     }
 
@@ -273,6 +293,7 @@ pub(crate) mod files {
             ) -> c_int,
         >,
         writer: &'a mut dyn PdfiumDocumentWriter, // Type-erased equivalent of <W: Write>
+        write_error: Option<std::io::Error>,      // The first error returned by the writer, if any
     }
 
     impl<'a> FpdfFileWriteExt<'a> {
@@ -283,6 +304,13 @@ pub(crate) mod files {
             unsafe { &mut *(self as *mut FpdfFileWriteExt as *mut FPDF_FILEWRITE) }
         }
 
+        /// Returns the first error returned by the underlying Rust writer during a save
+        /// operation, if any, clearing the stored error.
+        #[inline]
+        pub(crate) fn take_write_error(&mut self) -> Option<std::io::Error> {
+            self.write_error.take()
+        }
+
         /// Flushes the buffer of the underlying Rust writer.
         #[inline]
         pub(crate) fn flush(&mut self) -> std::io::Result<()> {
@@ -302,7 +330,16 @@ pub(crate) mod files {
                 .write_all(slice::from_raw_parts(buf as *const u8, size as usize))
             {
                 Ok(()) => 1,
-                Err(_) => 0,
+                Err(err) => {
+                    // Retain the error so it can be returned to the caller once Pdfium
+                    // has abandoned the save operation.
+
+                    if (*file_write_ext_ptr).write_error.is_none() {
+                        (*file_write_ext_ptr).write_error = Some(err);
+                    }
+
+                    0
+                }
             }
         };
 
@@ -310,6 +347,54 @@ pub(crate) mod files {
     }
 }
 
+pub(crate) mod pause {
+    use crate::bindgen::{FPDF_BOOL, IFSDK_PAUSE};
+    use std::os::raw::{c_int, c_void};
+    use std::ptr::null_mut;
+
+    /// Returns a wrapped Pdfium `IFSDK_PAUSE` struct that uses the given callback to decide
+    /// whether Pdfium should pause a progressive operation.
+    pub(crate) fn get_pdfium_pause_from_callback(
+        should_pause: &mut dyn FnMut() -> bool,
+    ) -> IfsdkPauseExt<'_> {
+        IfsdkPauseExt {
+            version: 1,
+            need_to_pause_now: Some(need_to_pause_now_from_callback),
+            user: null_mut(),
+            should_pause,
+        }
+    }
+
+    #[repr(C)]
+    pub(crate) struct IfsdkPauseExt<'a> {
+        // An extension of Pdfium's IFSDK_PAUSE struct that adds an extra field to carry the
+        // user-provided Rust callback.
+        version: c_int,
+        need_to_pause_now:
+            Option<unsafe extern "C" fn(pause_ext_ptr: *mut IfsdkPauseExt) -> FPDF_BOOL>,
+        user: *mut c_void,
+        should_pause: &'a mut dyn FnMut() -> bool,
+    }
+
+    impl<'a> IfsdkPauseExt<'a> {
+        /// Returns an `IFSDK_PAUSE` pointer suitable for passing to `FPDF_RenderPageBitmap_Start()`
+        /// or `FPDF_RenderPageBitmapWithColorScheme_Start()`.
+        #[inline]
+        pub(crate) fn as_ifsdk_pause_mut_ptr(&mut self) -> &mut IFSDK_PAUSE {
+            unsafe { &mut *(self as *mut IfsdkPauseExt as *mut IFSDK_PAUSE) }
+        }
+    }
+
+    // The callback function invoked by Pdfium.
+    pub(crate) extern "C" fn need_to_pause_now_from_callback(
+        pause_ext_ptr: *mut IfsdkPauseExt,
+    ) -> FPDF_BOOL {
+        let result = unsafe { ((*pause_ext_ptr).should_pause)() };
+
+        result as FPDF_BOOL
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     // Provides a function that binds to the correct Pdfium configuration during unit tests,
@@ -1,12 +1,13 @@
 use crate::bindgen::{
     size_t, FPDFANNOT_COLORTYPE, FPDF_ACTION, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE,
     FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
-    FPDF_CLIPPATH, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD, FPDF_FILEACCESS,
-    FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO, FPDF_FORMHANDLE, FPDF_GLYPHPATH,
-    FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE, FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT,
-    FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE, FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE,
-    FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR,
-    FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF,
+    FPDF_CLIPPATH, FPDF_COLORSCHEME, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD,
+    FPDF_FILEACCESS, FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO,
+    FPDF_FORMHANDLE, FPDF_GLYPHPATH, FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE,
+    FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE,
+    FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRUCTELEMENT, FPDF_STRUCTTREE,
+    FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FPDF_XOBJECT, FS_FLOAT,
+    FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF, FS_SIZEF, IFSDK_PAUSE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
@@ -1862,6 +1863,50 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .unwrap() as c_int
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_GetPageSizeByIndexF(
+        &self,
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        size: *mut FS_SIZEF,
+    ) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_GetPageSizeByIndexF()");
+
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_GetPageSizeByIndexF()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let buffer_length = size_of::<FS_SIZEF>();
+
+        let buffer_ptr = state.malloc(buffer_length);
+
+        let result = state
+            .call(
+                "FPDF_GetPageSizeByIndexF",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of3(
+                    &Self::js_value_from_document(document),
+                    &JsValue::from(page_index),
+                    &Self::js_value_from_offset(buffer_ptr),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL;
+
+        if self.is_true(result) {
+            state.copy_struct_from_pdfium(buffer_ptr, buffer_length, size);
+        }
+
+        state.free(buffer_ptr);
+
+        result
+    }
+
     #[allow(non_snake_case)]
     fn FPDF_LoadPage(&self, document: FPDF_DOCUMENT, page_index: c_int) -> FPDF_PAGE {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_LoadPage()");
@@ -2013,6 +2058,65 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .unwrap() as usize as FPDF_DOCUMENT
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_NewXObjectFromPage(
+        &self,
+        dest_doc: FPDF_DOCUMENT,
+        src_doc: FPDF_DOCUMENT,
+        src_page_index: c_int,
+    ) -> FPDF_XOBJECT {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_NewXObjectFromPage()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDF_NewXObjectFromPage",
+                JsFunctionArgumentType::Pointer,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of3(
+                    &Self::js_value_from_document(dest_doc),
+                    &Self::js_value_from_document(src_doc),
+                    &JsValue::from(src_page_index),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_XOBJECT
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_CloseXObject(&self, xobject: FPDF_XOBJECT) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_CloseXObject()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FPDF_CloseXObject",
+            JsFunctionArgumentType::Void,
+            Some(vec![JsFunctionArgumentType::Pointer]),
+            Some(&JsValue::from(Array::of1(&Self::js_value_from_offset(
+                xobject as usize,
+            )))),
+        );
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_NewFormObjectFromXObject(&self, xobject: FPDF_XOBJECT) -> FPDF_PAGEOBJECT {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_NewFormObjectFromXObject()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDF_NewFormObjectFromXObject",
+                JsFunctionArgumentType::Pointer,
+                Some(vec![JsFunctionArgumentType::Pointer]),
+                Some(&JsValue::from(Array::of1(&Self::js_value_from_offset(
+                    xobject as usize,
+                )))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_PAGEOBJECT
+    }
+
     #[allow(non_snake_case)]
     fn FPDF_GetPageWidthF(&self, page: FPDF_PAGE) -> c_float {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_GetPageWidthF()");
@@ -3663,6 +3767,235 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         state.free(ptr_clipping);
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmap_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RenderPageBitmap_Start()");
+
+        // Pause callbacks cannot be passed across the WASM boundary, so the given pause argument
+        // is ignored. Pdfium rejects a null pause, so we pass a version 1 pause of our own that
+        // has no callback; Pdfium never pauses rendering without a callback, so rendering is
+        // always performed in a single uninterrupted pass.
+
+        let _ = pause;
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let ptr_pause = state.copy_struct_to_pdfium(&IFSDK_PAUSE {
+            version: 1,
+            NeedToPauseNow: None,
+            user: std::ptr::null_mut(),
+        });
+
+        let result = state
+            .call(
+                "FPDF_RenderPageBitmap_Start",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Self::js_array_from_vec(vec![
+                    Self::js_value_from_bitmap(bitmap),
+                    Self::js_value_from_page(page),
+                    JsValue::from(start_x),
+                    JsValue::from(start_y),
+                    JsValue::from(size_x),
+                    JsValue::from(size_y),
+                    JsValue::from(rotate),
+                    JsValue::from(flags),
+                    Self::js_value_from_offset(ptr_pause),
+                ]))),
+            )
+            .as_f64()
+            .unwrap() as c_int;
+
+        state.free(ptr_pause);
+
+        result
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmapWithColorScheme_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        color_scheme: *const FPDF_COLORSCHEME,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDF_RenderPageBitmapWithColorScheme_Start()"
+        );
+
+        // Pause callbacks cannot be passed across the WASM boundary, so the given pause argument
+        // is ignored. Pdfium rejects a null pause, so we pass a version 1 pause of our own that
+        // has no callback; Pdfium never pauses rendering without a callback, so rendering is
+        // always performed in a single uninterrupted pass.
+
+        let _ = pause;
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let ptr_pause = state.copy_struct_to_pdfium(&IFSDK_PAUSE {
+            version: 1,
+            NeedToPauseNow: None,
+            user: std::ptr::null_mut(),
+        });
+
+        let ptr_color_scheme = state.copy_struct_to_pdfium(color_scheme);
+
+        let result = state
+            .call(
+                "FPDF_RenderPageBitmapWithColorScheme_Start",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Self::js_array_from_vec(vec![
+                    Self::js_value_from_bitmap(bitmap),
+                    Self::js_value_from_page(page),
+                    JsValue::from(start_x),
+                    JsValue::from(start_y),
+                    JsValue::from(size_x),
+                    JsValue::from(size_y),
+                    JsValue::from(rotate),
+                    JsValue::from(flags),
+                    Self::js_value_from_offset(ptr_color_scheme),
+                    Self::js_value_from_offset(ptr_pause),
+                ]))),
+            )
+            .as_f64()
+            .unwrap() as c_int;
+
+        state.free(ptr_color_scheme);
+
+        state.free(ptr_pause);
+
+        result
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RenderPage_Close()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FPDF_RenderPage_Close",
+            JsFunctionArgumentType::Void,
+            Some(vec![JsFunctionArgumentType::Pointer]),
+            Some(&JsValue::from(Array::of1(&Self::js_value_from_page(page)))),
+        );
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_PageToDevice(
+        &self,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        page_x: c_double,
+        page_y: c_double,
+        device_x: *mut c_int,
+        device_y: *mut c_int,
+    ) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_PageToDevice()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let len = size_of::<c_int>();
+
+        let ptr_device_x = state.malloc(len);
+
+        let ptr_device_y = state.malloc(len);
+
+        let result = state
+            .call(
+                "FPDF_PageToDevice",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Self::js_array_from_vec(vec![
+                    Self::js_value_from_page(page),
+                    JsValue::from(start_x),
+                    JsValue::from(start_y),
+                    JsValue::from(size_x),
+                    JsValue::from(size_y),
+                    JsValue::from(rotate),
+                    JsValue::from_f64(page_x),
+                    JsValue::from_f64(page_y),
+                    Self::js_value_from_offset(ptr_device_x),
+                    Self::js_value_from_offset(ptr_device_y),
+                ]))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL;
+
+        if self.is_true(result) {
+            unsafe {
+                *device_x = state
+                    .copy_bytes_from_pdfium(ptr_device_x, len)
+                    .try_into()
+                    .map(c_int::from_le_bytes)
+                    .unwrap_or(0);
+
+                *device_y = state
+                    .copy_bytes_from_pdfium(ptr_device_y, len)
+                    .try_into()
+                    .map(c_int::from_le_bytes)
+                    .unwrap_or(0);
+            }
+        }
+
+        state.free(ptr_device_x);
+        state.free(ptr_device_y);
+
+        result
+    }
+
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAnnot_IsSupportedSubtype()");
@@ -5724,6 +6057,163 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         );
     }
 
+    #[allow(non_snake_case)]
+    fn FORM_OnAfterLoadPage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_OnAfterLoadPage()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FORM_OnAfterLoadPage",
+            JsFunctionArgumentType::Void,
+            Some(vec![
+                JsFunctionArgumentType::Pointer,
+                JsFunctionArgumentType::Pointer,
+            ]),
+            Some(&JsValue::from(Array::of2(
+                &Self::js_value_from_page(page),
+                &Self::js_value_from_form(handle),
+            ))),
+        );
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_OnBeforeClosePage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_OnBeforeClosePage()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FORM_OnBeforeClosePage",
+            JsFunctionArgumentType::Void,
+            Some(vec![
+                JsFunctionArgumentType::Pointer,
+                JsFunctionArgumentType::Pointer,
+            ]),
+            Some(&JsValue::from(Array::of2(
+                &Self::js_value_from_page(page),
+                &Self::js_value_from_form(handle),
+            ))),
+        );
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_SetFocusedAnnot(&self, handle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_SetFocusedAnnot()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FORM_SetFocusedAnnot",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_form(handle),
+                    &Self::js_value_from_annotation(annot),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_SelectAllText(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_SelectAllText()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FORM_SelectAllText",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_form(hHandle),
+                    &Self::js_value_from_page(page),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_ReplaceSelection(
+        &self,
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        wsText: FPDF_WIDESTRING,
+    ) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_ReplaceSelection()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let text_ptr = state.copy_struct_to_pdfium(wsText);
+
+        state.call(
+            "FORM_ReplaceSelection",
+            JsFunctionArgumentType::Void,
+            Some(vec![
+                JsFunctionArgumentType::Pointer,
+                JsFunctionArgumentType::Pointer,
+                JsFunctionArgumentType::Pointer,
+            ]),
+            Some(&JsValue::from(Array::of3(
+                &Self::js_value_from_form(hHandle),
+                &Self::js_value_from_page(page),
+                &Self::js_value_from_offset(text_ptr),
+            ))),
+        );
+
+        state.free(text_ptr);
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_ForceToKillFocus(&self, hHandle: FPDF_FORMHANDLE) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_ForceToKillFocus()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FORM_ForceToKillFocus",
+                JsFunctionArgumentType::Number,
+                Some(vec![JsFunctionArgumentType::Pointer]),
+                Some(&JsValue::from(Array::of1(&Self::js_value_from_form(
+                    hHandle,
+                )))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL
+    }
+
+    #[allow(non_snake_case)]
+    fn FORM_OnChar(
+        &self,
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        nChar: c_int,
+        modifier: c_int,
+    ) -> FPDF_BOOL {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FORM_OnChar()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FORM_OnChar",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of4(
+                    &Self::js_value_from_form(hHandle),
+                    &Self::js_value_from_page(page),
+                    &JsValue::from(nChar),
+                    &JsValue::from(modifier),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_BOOL
+    }
+
     #[allow(non_snake_case)]
     fn FPDF_FFLDraw(
         &self,